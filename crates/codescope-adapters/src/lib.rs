@@ -1,9 +1,16 @@
+pub mod registry;
 pub mod typescript;
 
 #[cfg(test)]
 #[path = "typescript_test.rs"]
 mod typescript_test;
 
+#[cfg(test)]
+#[path = "registry_test.rs"]
+mod registry_test;
+
+pub use registry::AdapterRegistry;
+
 use codescope_core::types::{ModuleIR, Result};
 use std::path::Path;
 
@@ -0,0 +1,68 @@
+use crate::typescript::TypeScriptAdapter;
+use crate::LanguageAdapter;
+use codescope_core::types::{ModuleIR, Result};
+use std::path::Path;
+
+/// Dispatches files to the language adapter registered for their extension.
+pub struct AdapterRegistry {
+    adapters: Vec<Box<dyn LanguageAdapter>>,
+}
+
+impl AdapterRegistry {
+    pub fn new() -> Self {
+        Self {
+            adapters: Vec::new(),
+        }
+    }
+
+    /// Registry with the built-in TypeScript, TSX and JavaScript grammars.
+    pub fn with_defaults() -> Result<Self> {
+        let mut registry = Self::new();
+        registry.register(Box::new(TypeScriptAdapter::new_typescript()?));
+        registry.register(Box::new(TypeScriptAdapter::new_tsx()?));
+        registry.register(Box::new(TypeScriptAdapter::new_javascript()?));
+        Ok(registry)
+    }
+
+    /// Adapters registered later take precedence for extensions claimed more than once.
+    pub fn register(&mut self, adapter: Box<dyn LanguageAdapter>) {
+        self.adapters.push(adapter);
+    }
+
+    pub fn adapter_for(&self, path: &Path) -> Option<&dyn LanguageAdapter> {
+        let ext = path.extension()?.to_str()?;
+        self.adapters
+            .iter()
+            .rev()
+            .find(|adapter| adapter.match_ext().contains(&ext))
+            .map(|adapter| adapter.as_ref())
+    }
+
+    pub fn supports(&self, path: &Path) -> bool {
+        self.adapter_for(path).is_some()
+    }
+
+    pub fn extensions(&self) -> Vec<&'static str> {
+        let mut extensions: Vec<_> = self
+            .adapters
+            .iter()
+            .flat_map(|adapter| adapter.match_ext().iter().copied())
+            .collect();
+        extensions.sort_unstable();
+        extensions.dedup();
+        extensions
+    }
+
+    pub fn parse(&self, path: &Path, source: &str) -> Result<ModuleIR> {
+        let adapter = self
+            .adapter_for(path)
+            .ok_or_else(|| anyhow::anyhow!("Unsupported file type: {}", path.display()))?;
+        adapter.parse(path, source)
+    }
+}
+
+impl Default for AdapterRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
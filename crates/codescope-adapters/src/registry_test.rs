@@ -0,0 +1,66 @@
+#[cfg(test)]
+mod tests {
+    use crate::registry::AdapterRegistry;
+    use codescope_core::types::SymbolKind;
+    use std::path::Path;
+
+    #[test]
+    fn test_dispatch_by_extension() {
+        let registry = AdapterRegistry::with_defaults().unwrap();
+
+        for ext in ["ts", "mts", "cts", "tsx", "js", "jsx", "mjs", "cjs"] {
+            let path = format!("file.{}", ext);
+            let adapter = registry.adapter_for(Path::new(&path));
+            assert!(adapter.is_some(), "no adapter for .{}", ext);
+            assert!(adapter.unwrap().match_ext().contains(&ext));
+        }
+
+        assert!(!registry.supports(Path::new("style.css")));
+        assert!(!registry.supports(Path::new("Makefile")));
+        assert!(registry.parse(Path::new("style.css"), "body {}").is_err());
+    }
+
+    #[test]
+    fn test_tsx_uses_jsx_aware_grammar() {
+        let registry = AdapterRegistry::with_defaults().unwrap();
+        let source = r#"
+const UserCard = ({ user }: Props) => {
+    return <div className="card">{user.name}</div>;
+};
+
+function createTodo(text: string): Todo {
+    return { text };
+}
+"#;
+        let module = registry.parse(Path::new("UserCard.tsx"), source).unwrap();
+
+        let names: Vec<_> = module.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["UserCard", "createTodo"]);
+        assert_eq!(module.language.as_deref(), Some("tsx"));
+    }
+
+    #[test]
+    fn test_jsx_uses_javascript_grammar() {
+        let registry = AdapterRegistry::with_defaults().unwrap();
+        let source = r#"
+export function App({ items }) {
+    return <ul>{items.map(item => <li key={item.id}>{item.label}</li>)}</ul>;
+}
+"#;
+        let module = registry.parse(Path::new("App.jsx"), source).unwrap();
+
+        assert_eq!(module.symbols.len(), 1);
+        assert_eq!(module.symbols[0].kind, SymbolKind::Function);
+        assert_eq!(module.symbols[0].name, "App");
+    }
+
+    #[test]
+    fn test_extensions_are_deduplicated() {
+        let registry = AdapterRegistry::with_defaults().unwrap();
+        let extensions = registry.extensions();
+
+        assert_eq!(extensions.len(), 8);
+        assert!(extensions.contains(&"tsx"));
+        assert!(AdapterRegistry::new().extensions().is_empty());
+    }
+}
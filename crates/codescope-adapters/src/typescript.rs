@@ -1,96 +1,207 @@
 use crate::LanguageAdapter;
 use codescope_core::{
-    metrics::count_lines,
-    types::{DepEdge, DepKind, ModuleIR, Result, Symbol, SymbolKind},
+    metrics::{count_lines, import_strength},
+    types::{
+        BindingKind, DepEdge, DepKind, Export, ImportBinding, ImportKind, ModuleIR, Result, Span,
+        Symbol, SymbolKind, Visibility,
+    },
 };
 use std::path::Path;
 use tree_sitter::Parser;
 
 pub struct TypeScriptAdapter {
     language: tree_sitter::Language,
+    extensions: &'static [&'static str],
 }
 
 impl TypeScriptAdapter {
     pub fn new_typescript() -> Result<Self> {
         Ok(Self {
             language: tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            extensions: &["ts", "mts", "cts"],
         })
     }
 
     pub fn new_tsx() -> Result<Self> {
         Ok(Self {
             language: tree_sitter_typescript::LANGUAGE_TSX.into(),
+            extensions: &["tsx"],
         })
     }
 
     pub fn new_javascript() -> Result<Self> {
         Ok(Self {
             language: tree_sitter_javascript::LANGUAGE.into(),
+            extensions: &["js", "jsx", "mjs", "cjs"],
         })
     }
 
-    fn extract_symbols(&self, source: &str, tree: &tree_sitter::Tree) -> Result<Vec<Symbol>> {
+    fn extract_symbols(
+        &self,
+        source: &str,
+        tree: &tree_sitter::Tree,
+        exports: &[Export],
+    ) -> Result<Vec<Symbol>> {
         let mut symbols = Vec::new();
 
         // For now, return a simplified version to get compilation working
         // We'll enhance this later with proper tree-sitter queries
         let root = tree.root_node();
-        self.walk_node(root, source, &mut symbols);
+        self.walk_node(root, source, None, exports, &mut symbols);
 
         Ok(symbols)
     }
 
-    fn walk_node(&self, node: tree_sitter::Node, source: &str, symbols: &mut Vec<Symbol>) {
-        let kind_str = node.kind();
-
-        let (kind, name_field) = match kind_str {
-            "class_declaration" => (Some(SymbolKind::Class), "name"),
-            "function_declaration" => (Some(SymbolKind::Function), "name"),
-            "interface_declaration" => (Some(SymbolKind::Interface), "name"),
-            "type_alias_declaration" => (Some(SymbolKind::Type), "name"),
-            "enum_declaration" => (Some(SymbolKind::Enum), "name"),
-            _ => (None, ""),
+    fn walk_node(
+        &self,
+        node: tree_sitter::Node,
+        source: &str,
+        class_scope: Option<&str>,
+        exports: &[Export],
+        symbols: &mut Vec<Symbol>,
+    ) {
+        let kind = match node.kind() {
+            "class_declaration" | "abstract_class_declaration" => Some(SymbolKind::Class),
+            "class" if node.is_named() => Some(SymbolKind::Class),
+            "function_declaration" | "generator_function_declaration" => Some(SymbolKind::Function),
+            "method_definition" if is_class_member(node) => Some(SymbolKind::Method),
+            "method_definition" => Some(SymbolKind::Function),
+            "arrow_function" | "function_expression" | "generator_function" => {
+                if is_field_initializer(node) {
+                    Some(SymbolKind::Method)
+                } else {
+                    Some(SymbolKind::Function)
+                }
+            }
+            "interface_declaration" => Some(SymbolKind::Interface),
+            "type_alias_declaration" => Some(SymbolKind::Type),
+            "enum_declaration" => Some(SymbolKind::Enum),
+            _ => None,
         };
 
+        let mut class_name = None;
+
         if let Some(kind) = kind {
-            if let Some(name_node) = node.child_by_field_name(name_field) {
-                if let Ok(name) = name_node.utf8_text(source.as_bytes()) {
-                    let start = node.start_position().row;
-                    let end = node.end_position().row;
-                    let loc = (end - start + 1) as u32;
-
-                    let cyclomatic_complexity = if kind == SymbolKind::Function {
-                        Some(self.calculate_complexity(node, source))
-                    } else {
-                        None
-                    };
+            if let Some(name) = self.symbol_name(node, source) {
+                let start = node.start_position().row;
+                let end = node.end_position().row;
+                let loc = (end - start + 1) as u32;
 
-                    let symbol = Symbol {
-                        kind,
-                        name: name.to_string(),
-                        loc,
-                        cyclomatic_complexity,
-                        metrics: vec![],
-                    };
-                    symbols.push(symbol);
+                let cyclomatic_complexity = if kind.is_callable() {
+                    Some(self.calculate_complexity(node))
+                } else {
+                    None
+                };
+
+                let (name, parent) = match (&kind, class_scope) {
+                    (SymbolKind::Method, Some(class)) => {
+                        (format!("{}.{}", class, name), Some(class.to_string()))
+                    }
+                    _ => (name, None),
+                };
+
+                if kind == SymbolKind::Class {
+                    class_name = Some(name.clone());
                 }
+
+                let visibility = if parent.is_none() && is_top_level(node) {
+                    visibility_of(&name, exports)
+                } else {
+                    Visibility::Internal
+                };
+
+                let symbol = Symbol {
+                    kind,
+                    name,
+                    loc,
+                    cyclomatic_complexity,
+                    parent,
+                    visibility,
+                    is_abstract: node.kind() == "abstract_class_declaration",
+                    span: Some(span_of(node)),
+                    metrics: vec![],
+                };
+                symbols.push(symbol);
             }
         }
 
+        // Only direct members of a class body are qualified with the class name;
+        // functions nested inside a method are reported on their own.
+        let child_scope = match node.kind() {
+            "class_body" | "public_field_definition" => class_scope,
+            _ => class_name.as_deref(),
+        };
+
         for i in 0..node.child_count() {
             if let Some(child) = node.child(i) {
-                self.walk_node(child, source, symbols);
+                self.walk_node(child, source, child_scope, exports, symbols);
+            }
+        }
+    }
+
+    fn symbol_name(&self, node: tree_sitter::Node, source: &str) -> Option<String> {
+        match node.kind() {
+            "arrow_function" | "function_expression" | "generator_function" | "class" => self
+                .binding_name(node, source)
+                .or_else(|| node_text(node.child_by_field_name("name")?, source)),
+            // A getter and setter pair are two symbols: `get size` and `set size`
+            "method_definition" => {
+                let name = node_text(node.child_by_field_name("name")?, source)?;
+                match ["get", "set"]
+                    .into_iter()
+                    .find(|keyword| find_child(node, keyword).is_some())
+                {
+                    Some(keyword) => Some(format!("{} {}", keyword, name)),
+                    None => Some(name),
+                }
             }
+            _ => node_text(node.child_by_field_name("name")?, source),
         }
     }
 
-    fn calculate_complexity(&self, node: tree_sitter::Node, source: &str) -> u32 {
+    /// Name for a function or class value taken from where it is bound, e.g.
+    /// `const useTodos = () => {}`, `{ format: () => {} }` or `exports.run = function () {}`.
+    /// Default exports and IIFEs get a synthesized name such as `<default export>` or
+    /// `<iife@L12>`. Values that are not bound anywhere (callbacks, arguments) have no name.
+    fn binding_name(&self, node: tree_sitter::Node, source: &str) -> Option<String> {
+        let parent = node.parent()?;
+        match parent.kind() {
+            "variable_declarator" => {
+                let name = parent.child_by_field_name("name")?;
+                if name.kind() == "identifier" {
+                    node_text(name, source)
+                } else {
+                    None
+                }
+            }
+            "public_field_definition" => node_text(parent.child_by_field_name("name")?, source),
+            "pair" => node_text(parent.child_by_field_name("key")?, source),
+            "assignment_expression" if is_field(parent, "right", node) => {
+                node_text(parent.child_by_field_name("left")?, source)
+            }
+            "export_statement" if is_field(parent, "value", node) => {
+                Some(DEFAULT_EXPORT_NAME.to_string())
+            }
+            "call_expression" if is_field(parent, "function", node) => Some(iife_name(node)),
+            "parenthesized_expression" => {
+                let call = parent.parent()?;
+                if call.kind() == "call_expression" && is_field(call, "function", parent) {
+                    Some(iife_name(node))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn calculate_complexity(&self, node: tree_sitter::Node) -> u32 {
         let mut complexity = 1; // Base complexity
-        self.count_decision_points(node, source, &mut complexity);
+        self.count_decision_points(node, &mut complexity);
         complexity
     }
 
-    fn count_decision_points(&self, node: tree_sitter::Node, source: &str, complexity: &mut u32) {
+    fn count_decision_points(&self, node: tree_sitter::Node, complexity: &mut u32) {
         let kind = node.kind();
 
         match kind {
@@ -124,11 +235,49 @@ impl TypeScriptAdapter {
         // Recursively process children
         for i in 0..node.child_count() {
             if let Some(child) = node.child(i) {
-                self.count_decision_points(child, source, complexity);
+                self.count_decision_points(child, complexity);
             }
         }
     }
 
+    /// Names the module exports itself. Re-exports (`export ... from`) are import edges
+    /// and are collected by `extract_imports`.
+    fn extract_exports(&self, source: &str, tree: &tree_sitter::Tree) -> Result<Vec<Export>> {
+        let mut exports = Vec::new();
+        for statement in named_children(tree.root_node()) {
+            match statement.kind() {
+                "export_statement" if statement.child_by_field_name("source").is_none() => {
+                    collect_export_statement(statement, source, &mut exports);
+                }
+                "expression_statement" => {
+                    collect_commonjs_export(statement, source, &mut exports);
+                }
+                _ => {}
+            }
+        }
+        Ok(exports)
+    }
+
+    /// Whether `module.exports` or TypeScript `export =` is assigned something other than
+    /// an object literal, whose properties would be the exported names
+    fn has_dynamic_exports(&self, source: &str, tree: &tree_sitter::Tree) -> bool {
+        named_children(tree.root_node()).any(|statement| match statement.kind() {
+            "export_statement" => find_child(statement, "=").is_some(),
+            "expression_statement" => statement
+                .named_child(0)
+                .filter(|child| child.kind() == "assignment_expression")
+                .filter(|assignment| {
+                    assignment
+                        .child_by_field_name("left")
+                        .and_then(|left| node_text(left, source))
+                        .is_some_and(|target| target == "module.exports")
+                })
+                .and_then(|assignment| assignment.child_by_field_name("right"))
+                .is_some_and(|right| right.kind() != "object"),
+            _ => false,
+        })
+    }
+
     fn extract_imports(&self, source: &str, tree: &tree_sitter::Tree) -> Result<Vec<DepEdge>> {
         let mut deps = Vec::new();
         let root = tree.root_node();
@@ -137,22 +286,62 @@ impl TypeScriptAdapter {
     }
 
     fn walk_imports(&self, node: tree_sitter::Node, source: &str, deps: &mut Vec<DepEdge>) {
-        if node.kind() == "import_statement" {
-            if let Some(source_node) = node.child_by_field_name("source") {
-                if let Ok(import_path) = source_node.utf8_text(source.as_bytes()) {
-                    // Remove quotes from string
-                    let cleaned = import_path.trim_matches(|c| c == '"' || c == '\'');
-                    if !cleaned.is_empty() {
-                        deps.push(DepEdge {
-                            source: None,
-                            target: Some(cleaned.to_string()),
-                            relation: DepKind::Import,
-                            strength: 0.7,
-                            files: None,
-                        });
+        match node.kind() {
+            "import_statement" => {
+                if let Some(require) = find_child(node, "import_require_clause") {
+                    // TypeScript `import fs = require("fs")`
+                    if let Some(specifier) = require.child_by_field_name("source") {
+                        let bindings = find_child(require, "identifier")
+                            .and_then(|local| node_text(local, source))
+                            .map(|local| vec![namespace_binding(local)])
+                            .unwrap_or_default();
+                        let site = ImportSite::new(node, ImportKind::Require, false);
+                        push_import(deps, specifier, source, site, bindings);
                     }
+                } else if let Some(specifier) = node.child_by_field_name("source") {
+                    let statement_type_only = has_type_keyword(node);
+                    let bindings = match find_child(node, "import_clause") {
+                        Some(clause) => import_clause_bindings(clause, source, statement_type_only),
+                        None => Vec::new(),
+                    };
+                    let kind = if find_child(node, "import_clause").is_some() {
+                        ImportKind::Static
+                    } else {
+                        ImportKind::SideEffect
+                    };
+                    // `import { type A, type B }` is erased at runtime just like `import type`
+                    let type_only = statement_type_only
+                        || (!bindings.is_empty() && bindings.iter().all(|b| b.type_only));
+                    let site = ImportSite::new(node, kind, type_only);
+                    push_import(deps, specifier, source, site, bindings);
+                }
+            }
+            "export_statement" => {
+                // Re-exports: `export * from "x"`, `export { a } from "x"`
+                if let Some(specifier) = node.child_by_field_name("source") {
+                    let type_only = has_type_keyword(node);
+                    let bindings = reexport_bindings(node, source, type_only);
+                    let site = ImportSite::new(node, ImportKind::ReExport, type_only);
+                    push_import(deps, specifier, source, site, bindings);
                 }
             }
+            "call_expression" => {
+                let kind = match node.child_by_field_name("function") {
+                    Some(f) if f.kind() == "import" => Some(ImportKind::Dynamic),
+                    Some(f) if is_require(f, source) => Some(ImportKind::Require),
+                    _ => None,
+                };
+                let specifier = node
+                    .child_by_field_name("arguments")
+                    .and_then(|args| args.named_child(0))
+                    .filter(|arg| arg.kind() == "string");
+                if let (Some(kind), Some(specifier)) = (kind, specifier) {
+                    let bindings = declarator_bindings(node, source);
+                    let site = ImportSite::new(node, kind, false);
+                    push_import(deps, specifier, source, site, bindings);
+                }
+            }
+            _ => {}
         }
 
         for i in 0..node.child_count() {
@@ -163,9 +352,474 @@ impl TypeScriptAdapter {
     }
 }
 
+/// Where and how a dependency is declared
+struct ImportSite<'a> {
+    node: tree_sitter::Node<'a>,
+    kind: ImportKind,
+    type_only: bool,
+}
+
+impl<'a> ImportSite<'a> {
+    fn new(node: tree_sitter::Node<'a>, kind: ImportKind, type_only: bool) -> Self {
+        Self {
+            node,
+            kind,
+            type_only,
+        }
+    }
+}
+
+fn push_import(
+    deps: &mut Vec<DepEdge>,
+    specifier: tree_sitter::Node,
+    source: &str,
+    site: ImportSite,
+    bindings: Vec<ImportBinding>,
+) {
+    if let Some(import_path) = node_text(specifier, source) {
+        // Remove quotes from string
+        let cleaned = import_path.trim_matches(|c| c == '"' || c == '\'');
+        if !cleaned.is_empty() {
+            let mut edge = DepEdge {
+                source: None,
+                target: Some(cleaned.to_string()),
+                relation: DepKind::Import,
+                strength: 0.0,
+                files: None,
+                span: Some(span_of(site.node)),
+                import_kind: Some(site.kind),
+                type_only: site.type_only,
+                bindings,
+                specifier: Some(cleaned.to_string()),
+                resolution: None,
+            };
+            edge.strength = import_strength(&edge);
+            deps.push(edge);
+        }
+    }
+}
+
+fn binding(imported: String, local: String, type_only: bool) -> ImportBinding {
+    let kind = if imported == "default" {
+        BindingKind::Default
+    } else {
+        BindingKind::Named
+    };
+    ImportBinding {
+        kind,
+        imported,
+        local,
+        type_only,
+    }
+}
+
+fn namespace_binding(local: String) -> ImportBinding {
+    ImportBinding {
+        kind: BindingKind::Namespace,
+        imported: "*".to_string(),
+        local,
+        type_only: false,
+    }
+}
+
+/// `import def, * as ns, { a, b as c } from "x"`
+fn import_clause_bindings(
+    clause: tree_sitter::Node,
+    source: &str,
+    type_only: bool,
+) -> Vec<ImportBinding> {
+    let mut bindings = Vec::new();
+    for child in named_children(clause) {
+        match child.kind() {
+            "identifier" => {
+                if let Some(local) = node_text(child, source) {
+                    bindings.push(binding("default".to_string(), local, type_only));
+                }
+            }
+            "namespace_import" => {
+                if let Some(local) =
+                    find_child(child, "identifier").and_then(|id| node_text(id, source))
+                {
+                    bindings.push(ImportBinding {
+                        type_only,
+                        ..namespace_binding(local)
+                    });
+                }
+            }
+            "named_imports" => {
+                for specifier in named_children(child) {
+                    if specifier.kind() == "import_specifier" {
+                        let specifier_type_only = type_only || has_type_keyword(specifier);
+                        bindings.extend(specifier_binding(specifier, source, specifier_type_only));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+    bindings
+}
+
+/// `export * from "x"`, `export * as ns from "x"`, `export { a as b } from "x"`
+fn reexport_bindings(node: tree_sitter::Node, source: &str, type_only: bool) -> Vec<ImportBinding> {
+    if let Some(clause) = find_child(node, "export_clause") {
+        return named_children(clause)
+            .filter(|specifier| specifier.kind() == "export_specifier")
+            .filter_map(|specifier| {
+                let specifier_type_only = type_only || has_type_keyword(specifier);
+                specifier_binding(specifier, source, specifier_type_only)
+            })
+            .collect();
+    }
+    let local = match find_child(node, "namespace_export") {
+        Some(namespace) => find_child(namespace, "identifier").and_then(|id| node_text(id, source)),
+        None => find_child(node, "*").map(|_| "*".to_string()),
+    };
+    local
+        .map(|local| {
+            vec![ImportBinding {
+                type_only,
+                ..namespace_binding(local)
+            }]
+        })
+        .unwrap_or_default()
+}
+
+/// `a` or `a as b` inside `{ ... }` of an import or re-export
+fn specifier_binding(
+    specifier: tree_sitter::Node,
+    source: &str,
+    type_only: bool,
+) -> Option<ImportBinding> {
+    let imported = node_text(specifier.child_by_field_name("name")?, source)?;
+    let local = match specifier.child_by_field_name("alias") {
+        Some(alias) => node_text(alias, source)?,
+        None => imported.clone(),
+    };
+    Some(binding(imported, local, type_only))
+}
+
+/// Names bound from a `require()` or `await import()` result:
+/// `const fs = require("fs")`, `const { a, b: c } = await import("./x")`
+fn declarator_bindings(call: tree_sitter::Node, source: &str) -> Vec<ImportBinding> {
+    let mut value = call;
+    if let Some(parent) = value.parent().filter(|p| p.kind() == "await_expression") {
+        value = parent;
+    }
+    let Some(declarator) = value
+        .parent()
+        .filter(|p| p.kind() == "variable_declarator" && is_field(*p, "value", value))
+    else {
+        return Vec::new();
+    };
+    let Some(pattern) = declarator.child_by_field_name("name") else {
+        return Vec::new();
+    };
+
+    match pattern.kind() {
+        "identifier" => node_text(pattern, source)
+            .map(|local| vec![namespace_binding(local)])
+            .unwrap_or_default(),
+        "object_pattern" => named_children(pattern)
+            .filter_map(|property| match property.kind() {
+                "shorthand_property_identifier_pattern" => {
+                    let name = node_text(property, source)?;
+                    Some(binding(name.clone(), name, false))
+                }
+                "pair_pattern" => {
+                    let imported = node_text(property.child_by_field_name("key")?, source)?;
+                    let local = node_text(property.child_by_field_name("value")?, source)?;
+                    Some(binding(imported, local, false))
+                }
+                "rest_pattern" => {
+                    let local =
+                        find_child(property, "identifier").and_then(|id| node_text(id, source))?;
+                    Some(namespace_binding(local))
+                }
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Name of an anonymous function or class that is the default export (see `binding_name`)
+const DEFAULT_EXPORT_NAME: &str = "<default export>";
+
+fn collect_export_statement(statement: tree_sitter::Node, source: &str, exports: &mut Vec<Export>) {
+    let span = Some(span_of(statement));
+    let statement_type_only = has_type_keyword(statement);
+
+    if find_child(statement, "default").is_some() || find_child(statement, "=").is_some() {
+        // `export default <declaration | expression>`, TypeScript `export = <expression>`
+        let local = if let Some(declaration) = statement.child_by_field_name("declaration") {
+            declaration
+                .child_by_field_name("name")
+                .and_then(|name| node_text(name, source))
+        } else {
+            let value = statement
+                .child_by_field_name("value")
+                .or_else(|| named_children(statement).last());
+            match value {
+                Some(value) if value.kind() == "identifier" => node_text(value, source),
+                Some(value) if binds_default_export_name(value) => {
+                    Some(DEFAULT_EXPORT_NAME.to_string())
+                }
+                _ => None,
+            }
+        };
+        exports.push(Export {
+            name: "default".to_string(),
+            local,
+            type_only: false,
+            aggregate: false,
+            span,
+        });
+    } else if let Some(declaration) = statement.child_by_field_name("declaration") {
+        for (name, type_only) in declared_names(declaration, source) {
+            exports.push(Export {
+                local: Some(name.clone()),
+                name,
+                type_only,
+                aggregate: false,
+                span,
+            });
+        }
+    } else if let Some(clause) = find_child(statement, "export_clause") {
+        // `export { a, b as c }`
+        for specifier in named_children(clause) {
+            if let Some(binding) = specifier_binding(
+                specifier,
+                source,
+                statement_type_only || has_type_keyword(specifier),
+            ) {
+                exports.push(Export {
+                    name: binding.local,
+                    local: Some(binding.imported),
+                    type_only: binding.type_only,
+                    aggregate: false,
+                    span,
+                });
+            }
+        }
+    }
+}
+
+/// Anonymous values that `binding_name` reports as `<default export>`
+fn binds_default_export_name(value: tree_sitter::Node) -> bool {
+    matches!(
+        value.kind(),
+        "arrow_function" | "function_expression" | "generator_function" | "class"
+    ) && value.child_by_field_name("name").is_none()
+}
+
+/// Names introduced by an exported declaration, flagged when they only exist as types
+fn declared_names(declaration: tree_sitter::Node, source: &str) -> Vec<(String, bool)> {
+    match declaration.kind() {
+        "lexical_declaration" | "variable_declaration" => named_children(declaration)
+            .filter(|child| child.kind() == "variable_declarator")
+            .filter_map(|declarator| declarator.child_by_field_name("name"))
+            .flat_map(|pattern| pattern_names(pattern, source))
+            .map(|name| (name, false))
+            .collect(),
+        // `export declare ...`
+        "ambient_declaration" => named_children(declaration)
+            .flat_map(|inner| declared_names(inner, source))
+            .collect(),
+        kind => {
+            let type_only = matches!(kind, "interface_declaration" | "type_alias_declaration");
+            declaration
+                .child_by_field_name("name")
+                .and_then(|name| node_text(name, source))
+                .map(|name| vec![(name, type_only)])
+                .unwrap_or_default()
+        }
+    }
+}
+
+/// Identifiers bound by a declarator name: `a`, `{ a, b: c }`, `[a, b]`
+fn pattern_names(pattern: tree_sitter::Node, source: &str) -> Vec<String> {
+    match pattern.kind() {
+        "identifier" | "shorthand_property_identifier_pattern" => {
+            node_text(pattern, source).into_iter().collect()
+        }
+        "pair_pattern" => pattern
+            .child_by_field_name("value")
+            .map(|value| pattern_names(value, source))
+            .unwrap_or_default(),
+        "object_pattern" | "array_pattern" | "rest_pattern" | "assignment_pattern" => {
+            named_children(pattern)
+                .filter(|child| !is_field(pattern, "right", *child))
+                .flat_map(|child| pattern_names(child, source))
+                .collect()
+        }
+        _ => Vec::new(),
+    }
+}
+
+/// CommonJS exports: `module.exports = ...`, `module.exports.run = ...`, `exports.run = ...`
+fn collect_commonjs_export(statement: tree_sitter::Node, source: &str, exports: &mut Vec<Export>) {
+    let Some(assignment) = statement
+        .named_child(0)
+        .filter(|child| child.kind() == "assignment_expression")
+    else {
+        return;
+    };
+    let (Some(left), Some(right)) = (
+        assignment.child_by_field_name("left"),
+        assignment.child_by_field_name("right"),
+    ) else {
+        return;
+    };
+    let Some(target) = node_text(left, source) else {
+        return;
+    };
+    let span = Some(span_of(statement));
+
+    if target == "module.exports" {
+        let local = match right.kind() {
+            "identifier" => node_text(right, source),
+            _ => None,
+        };
+        exports.push(Export {
+            name: "default".to_string(),
+            local,
+            type_only: false,
+            aggregate: right.kind() == "object",
+            span,
+        });
+        if right.kind() == "object" {
+            // `module.exports = { parse, format: formatDate }`
+            for property in named_children(right) {
+                let (name, local) = match property.kind() {
+                    "shorthand_property_identifier" => {
+                        let name = node_text(property, source);
+                        (name.clone(), name)
+                    }
+                    "pair" => (
+                        property
+                            .child_by_field_name("key")
+                            .and_then(|key| node_text(key, source)),
+                        property
+                            .child_by_field_name("value")
+                            .filter(|value| value.kind() == "identifier")
+                            .and_then(|value| node_text(value, source)),
+                    ),
+                    _ => (None, None),
+                };
+                if let Some(name) = name {
+                    exports.push(Export {
+                        name,
+                        local,
+                        type_only: false,
+                        aggregate: false,
+                        span,
+                    });
+                }
+            }
+        }
+    } else if let Some(name) = target
+        .strip_prefix("module.exports.")
+        .or_else(|| target.strip_prefix("exports."))
+    {
+        // Functions assigned here are reported under the full target name
+        exports.push(Export {
+            name: name.to_string(),
+            local: Some(target.clone()),
+            type_only: false,
+            aggregate: false,
+            span,
+        });
+    }
+}
+
+/// Declared at module scope, e.g. not nested in a function or class body
+fn is_top_level(node: tree_sitter::Node) -> bool {
+    let mut current = node;
+    while let Some(parent) = current.parent() {
+        match parent.kind() {
+            "program" => return true,
+            "export_statement"
+            | "ambient_declaration"
+            | "lexical_declaration"
+            | "variable_declaration"
+            | "variable_declarator"
+            | "expression_statement"
+            | "assignment_expression" => current = parent,
+            _ => return false,
+        }
+    }
+    false
+}
+
+fn visibility_of(name: &str, exports: &[Export]) -> Visibility {
+    let mut visibility = Visibility::Internal;
+    for export in exports.iter().filter(|e| e.local.as_deref() == Some(name)) {
+        if export.name == "default" {
+            return Visibility::DefaultExport;
+        }
+        visibility = Visibility::Exported;
+    }
+    visibility
+}
+
+fn named_children<'a>(node: tree_sitter::Node<'a>) -> impl Iterator<Item = tree_sitter::Node<'a>> {
+    (0..node.named_child_count()).filter_map(move |i| node.named_child(i))
+}
+
+fn is_require(function: tree_sitter::Node, source: &str) -> bool {
+    function.kind() == "identifier" && node_text(function, source).as_deref() == Some("require")
+}
+
+fn find_child<'a>(node: tree_sitter::Node<'a>, kind: &str) -> Option<tree_sitter::Node<'a>> {
+    (0..node.child_count())
+        .filter_map(|i| node.child(i))
+        .find(|child| child.kind() == kind)
+}
+
+/// `import type ...` / `export type ... from`
+fn has_type_keyword(node: tree_sitter::Node) -> bool {
+    find_child(node, "type").is_some()
+}
+
+fn is_class_member(node: tree_sitter::Node) -> bool {
+    node.parent().is_some_and(|p| p.kind() == "class_body")
+}
+
+/// Class fields initialised with a function, e.g. `handleClick = () => {}`.
+fn is_field_initializer(node: tree_sitter::Node) -> bool {
+    node.parent()
+        .is_some_and(|p| p.kind() == "public_field_definition" && is_class_member(p))
+}
+
+fn is_field(parent: tree_sitter::Node, field: &str, node: tree_sitter::Node) -> bool {
+    parent.child_by_field_name(field) == Some(node)
+}
+
+fn iife_name(node: tree_sitter::Node) -> String {
+    format!("<iife@L{}>", node.start_position().row + 1)
+}
+
+fn span_of(node: tree_sitter::Node) -> Span {
+    let start = node.start_position();
+    let end = node.end_position();
+    Span {
+        start_line: start.row as u32 + 1,
+        start_column: start.column as u32 + 1,
+        end_line: end.row as u32 + 1,
+        end_column: end.column as u32 + 1,
+        start_byte: node.start_byte(),
+        end_byte: node.end_byte(),
+    }
+}
+
+fn node_text(node: tree_sitter::Node, source: &str) -> Option<String> {
+    node.utf8_text(source.as_bytes()).ok().map(String::from)
+}
+
 impl LanguageAdapter for TypeScriptAdapter {
     fn match_ext(&self) -> &'static [&'static str] {
-        &["ts", "tsx", "js"]
+        self.extensions
     }
 
     fn parse(&self, path: &Path, source: &str) -> Result<ModuleIR> {
@@ -177,8 +831,10 @@ impl LanguageAdapter for TypeScriptAdapter {
             .ok_or_else(|| anyhow::anyhow!("Failed to parse file"))?;
 
         let loc_stats = count_lines(source);
-        let symbols = self.extract_symbols(source, &tree)?;
+        let exports = self.extract_exports(source, &tree)?;
+        let symbols = self.extract_symbols(source, &tree, &exports)?;
         let outgoing = self.extract_imports(source, &tree)?;
+        let dynamic_exports = self.has_dynamic_exports(source, &tree);
 
         let module = ModuleIR {
             path: path.to_string_lossy().to_string(),
@@ -187,6 +843,8 @@ impl LanguageAdapter for TypeScriptAdapter {
             comment_lines: loc_stats.comment,
             blank_lines: loc_stats.blank,
             symbols,
+            exports,
+            dynamic_exports,
             metrics: vec![],
             outgoing,
             incoming: Vec::new(),
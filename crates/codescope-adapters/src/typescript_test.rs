@@ -2,7 +2,9 @@
 mod tests {
     use crate::typescript::TypeScriptAdapter;
     use crate::LanguageAdapter;
-    use codescope_core::types::SymbolKind;
+    use codescope_core::rules::api_surface::ApiSurfaceRule;
+    use codescope_core::rules::QualityRule;
+    use codescope_core::types::{BindingKind, ImportKind, SymbolKind, Visibility};
 
     #[test]
     fn test_complexity_simple_function() {
@@ -146,9 +148,623 @@ type MyType = string | number;
         let module = result.unwrap();
 
         for symbol in &module.symbols {
-            if symbol.kind != SymbolKind::Function {
+            if !symbol.kind.is_callable() {
                 assert_eq!(symbol.cyclomatic_complexity, None);
             }
         }
     }
+
+    #[test]
+    fn test_arrow_function_assigned_to_variable() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"
+const validateUser = (user: User) => {
+    if (!user.name) return false;
+    if (user.age < 0 || user.age > 150) return false;
+    return true;
+};
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        assert_eq!(module.symbols.len(), 1);
+        assert_eq!(module.symbols[0].kind, SymbolKind::Function);
+        assert_eq!(module.symbols[0].name, "validateUser");
+        assert_eq!(module.symbols[0].loc, 5);
+        // 1 base + 2 ifs + 1 ||
+        assert_eq!(module.symbols[0].cyclomatic_complexity, Some(4));
+    }
+
+    #[test]
+    fn test_function_expression_uses_variable_name() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"
+const factorial = function fact(n: number): number {
+    if (n <= 1) return 1;
+    return n * fact(n - 1);
+};
+let greet = function (name: string) {
+    return `Hello, ${name}`;
+};
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        let names: Vec<_> = module.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["factorial", "greet"]);
+        assert_eq!(module.symbols[0].cyclomatic_complexity, Some(2));
+    }
+
+    #[test]
+    fn test_anonymous_callbacks_are_not_symbols() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"
+const useTodos = (todos: Todo[]) => {
+    const handleClick = () => {
+        save(todos);
+    };
+    return todos.filter(todo => todo.done).map(todo => todo.id);
+};
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        let names: Vec<_> = module.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["useTodos", "handleClick"]);
+    }
+
+    #[test]
+    fn test_class_members_are_method_symbols() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"
+class DataStore {
+    private data: any[] = [];
+
+    constructor() {
+        this.data = [];
+    }
+
+    process(filter?: (item: any) => boolean) {
+        for (const item of this.data) {
+            if (filter && !filter(item)) continue;
+        }
+    }
+
+    private validate(item: any) {
+        return !!item;
+    }
+
+    get size(): number {
+        return this.data.length;
+    }
+
+    set size(value: number) {
+        this.data.length = value;
+    }
+
+    get(index: number) {
+        return this.data[index];
+    }
+
+    static create() {
+        return new DataStore();
+    }
+
+    handleClick = () => {
+        this.process();
+    };
+}
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        assert_eq!(module.symbols[0].kind, SymbolKind::Class);
+        assert_eq!(module.symbols[0].name, "DataStore");
+
+        let methods: Vec<_> = module
+            .symbols
+            .iter()
+            .filter(|s| s.kind == SymbolKind::Method)
+            .collect();
+        let names: Vec<_> = methods.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "DataStore.constructor",
+                "DataStore.process",
+                "DataStore.validate",
+                "DataStore.get size",
+                "DataStore.set size",
+                "DataStore.get",
+                "DataStore.create",
+                "DataStore.handleClick",
+            ]
+        );
+        assert!(methods
+            .iter()
+            .all(|m| m.parent.as_deref() == Some("DataStore")));
+
+        let process = methods[1];
+        assert_eq!(process.loc, 5);
+        // 1 base + 1 for + 1 if + 1 &&
+        assert_eq!(process.cyclomatic_complexity, Some(4));
+    }
+
+    #[test]
+    fn test_functions_nested_in_methods_are_not_qualified() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"
+abstract class Base {
+    abstract run(): void;
+
+    start() {
+        const tick = () => this.run();
+        tick();
+    }
+}
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        let names: Vec<_> = module.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["Base", "Base.start", "tick"]);
+        assert_eq!(module.symbols[2].kind, SymbolKind::Function);
+        assert_eq!(module.symbols[2].parent, None);
+    }
+
+    #[test]
+    fn test_iife_and_default_export_get_synthesized_names() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"
+const config = (function () {
+    const env = process.env.NODE_ENV || 'development';
+    return { env };
+})();
+
+export default function (data: any[]) {
+    if (!data || data.length === 0) {
+        return null;
+    }
+    return data;
+}
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        let names: Vec<_> = module.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["<iife@L2>", "<default export>"]);
+        assert_eq!(module.symbols[0].cyclomatic_complexity, Some(2));
+        assert_eq!(module.symbols[1].cyclomatic_complexity, Some(3));
+    }
+
+    #[test]
+    fn test_module_pattern_functions() {
+        let adapter = TypeScriptAdapter::new_javascript().unwrap();
+        let source = r#"
+const utils = {
+    format(value) {
+        return String(value);
+    },
+    parse: function (text) {
+        return JSON.parse(text);
+    },
+    noop: () => {},
+};
+
+module.exports.run = function () {
+    return utils.format(1);
+};
+
+items.forEach(function visit(item) {
+    console.log(item);
+});
+
+export default class {
+    render() {}
+}
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.js"), source)
+            .unwrap();
+
+        let names: Vec<_> = module.symbols.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec![
+                "format",
+                "parse",
+                "noop",
+                "module.exports.run",
+                "visit",
+                "<default export>",
+                "<default export>.render",
+            ]
+        );
+        assert_eq!(module.symbols[0].kind, SymbolKind::Function);
+        assert_eq!(module.symbols[5].kind, SymbolKind::Class);
+        assert_eq!(module.symbols[6].kind, SymbolKind::Method);
+    }
+
+    #[test]
+    fn test_symbol_and_import_spans() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"import { join } from "path";
+
+export function build(dir: string) {
+    return join(dir, "out");
+}
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        let span = module.symbols[0].span.unwrap();
+        assert_eq!((span.start_line, span.start_column), (3, 8));
+        assert_eq!((span.end_line, span.end_column), (5, 2));
+        assert_eq!(&source[span.start_byte..span.start_byte + 8], "function");
+
+        let import = module.outgoing[0].span.unwrap();
+        assert_eq!((import.start_line, import.start_column), (1, 1));
+        assert_eq!(
+            &source[import.start_byte..import.end_byte],
+            r#"import { join } from "path";"#
+        );
+    }
+
+    #[test]
+    fn test_import_styles() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"
+import { readFile } from "fs";
+import type { Config } from "./config";
+import { type User, type Role } from "./types";
+import { type Todo, createTodo } from "./todo";
+import "./polyfills";
+import path = require("path");
+export * from "./models";
+export { helper as util } from "./helpers";
+export type { Theme } from "./theme";
+
+const legacy = require("./legacy");
+
+async function load() {
+    const { Editor } = await import("./editor");
+    return new Editor();
+}
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        let edges: Vec<_> = module
+            .outgoing
+            .iter()
+            .map(|e| {
+                (
+                    e.target.as_deref().unwrap(),
+                    e.import_kind.unwrap(),
+                    e.type_only,
+                )
+            })
+            .collect();
+        assert_eq!(
+            edges,
+            vec![
+                ("fs", ImportKind::Static, false),
+                ("./config", ImportKind::Static, true),
+                ("./types", ImportKind::Static, true),
+                ("./todo", ImportKind::Static, false),
+                ("./polyfills", ImportKind::SideEffect, false),
+                ("path", ImportKind::Require, false),
+                ("./models", ImportKind::ReExport, false),
+                ("./helpers", ImportKind::ReExport, false),
+                ("./theme", ImportKind::ReExport, true),
+                ("./legacy", ImportKind::Require, false),
+                ("./editor", ImportKind::Dynamic, false),
+            ]
+        );
+
+        let config = &module.outgoing[1];
+        let editor = &module.outgoing[10];
+        assert!(config.strength < module.outgoing[0].strength);
+        assert!(editor.strength < module.outgoing[0].strength);
+    }
+
+    #[test]
+    fn test_require_in_javascript() {
+        let adapter = TypeScriptAdapter::new_javascript().unwrap();
+        let source = r#"
+const express = require('express');
+const { format } = require('./format');
+const name = require(dynamicName);
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("server.js"), source)
+            .unwrap();
+
+        let targets: Vec<_> = module
+            .outgoing
+            .iter()
+            .map(|e| e.target.as_deref().unwrap())
+            .collect();
+        assert_eq!(targets, vec!["express", "./format"]);
+        assert!(module
+            .outgoing
+            .iter()
+            .all(|e| e.import_kind == Some(ImportKind::Require)));
+    }
+
+    #[test]
+    fn test_import_bindings() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"
+import React, { useState, useEffect as onMount } from "react";
+import * as path from "path";
+import { type Props, render } from "./render";
+import "./styles.css";
+export * from "./models";
+export { helper as util, default as Button } from "./helpers";
+const { parse, stringify: toJson } = require("./json");
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        let bindings = |i: usize| -> Vec<(BindingKind, &str, &str, bool)> {
+            module.outgoing[i]
+                .bindings
+                .iter()
+                .map(|b| (b.kind, b.imported.as_str(), b.local.as_str(), b.type_only))
+                .collect()
+        };
+
+        assert_eq!(
+            bindings(0),
+            vec![
+                (BindingKind::Default, "default", "React", false),
+                (BindingKind::Named, "useState", "useState", false),
+                (BindingKind::Named, "useEffect", "onMount", false),
+            ]
+        );
+        assert_eq!(
+            bindings(1),
+            vec![(BindingKind::Namespace, "*", "path", false)]
+        );
+        assert_eq!(
+            bindings(2),
+            vec![
+                (BindingKind::Named, "Props", "Props", true),
+                (BindingKind::Named, "render", "render", false),
+            ]
+        );
+        assert!(!module.outgoing[2].type_only);
+        assert!(bindings(3).is_empty());
+        assert_eq!(module.outgoing[3].import_kind, Some(ImportKind::SideEffect));
+        assert_eq!(bindings(4), vec![(BindingKind::Namespace, "*", "*", false)]);
+        assert_eq!(
+            bindings(5),
+            vec![
+                (BindingKind::Named, "helper", "util", false),
+                (BindingKind::Default, "default", "Button", false),
+            ]
+        );
+        assert_eq!(
+            bindings(6),
+            vec![
+                (BindingKind::Named, "parse", "parse", false),
+                (BindingKind::Named, "stringify", "toJson", false),
+            ]
+        );
+
+        // Side-effect imports couple less than imports that bind names
+        assert!(module.outgoing[3].strength < module.outgoing[0].strength);
+    }
+
+    #[test]
+    fn test_export_surface() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"
+export function parse() {}
+export const format = () => {}, VERSION = 1;
+export interface Options {}
+export type Mode = "a" | "b";
+export default class Parser {}
+function helper() {}
+const tokenize = () => {};
+export { tokenize as lex };
+export { Writer } from "./writer";
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        let exports: Vec<(&str, Option<&str>, bool)> = module
+            .exports
+            .iter()
+            .map(|e| (e.name.as_str(), e.local.as_deref(), e.type_only))
+            .collect();
+        assert_eq!(
+            exports,
+            vec![
+                ("parse", Some("parse"), false),
+                ("format", Some("format"), false),
+                ("VERSION", Some("VERSION"), false),
+                ("Options", Some("Options"), true),
+                ("Mode", Some("Mode"), true),
+                ("default", Some("Parser"), false),
+                ("lex", Some("tokenize"), false),
+            ]
+        );
+
+        let visibility = |name: &str| {
+            module
+                .symbols
+                .iter()
+                .find(|s| s.name == name)
+                .unwrap()
+                .visibility
+        };
+        assert_eq!(visibility("parse"), Visibility::Exported);
+        assert_eq!(visibility("format"), Visibility::Exported);
+        assert_eq!(visibility("Options"), Visibility::Exported);
+        assert_eq!(visibility("Parser"), Visibility::DefaultExport);
+        assert_eq!(visibility("tokenize"), Visibility::Exported);
+        assert_eq!(visibility("helper"), Visibility::Internal);
+    }
+
+    #[test]
+    fn test_export_surface_nested_symbols_are_internal() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"
+export default function () {
+    function inner() {}
+}
+export class Store {
+    load() {}
+}
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        let visibility = |name: &str| {
+            module
+                .symbols
+                .iter()
+                .find(|s| s.name == name)
+                .unwrap()
+                .visibility
+        };
+        assert_eq!(visibility("<default export>"), Visibility::DefaultExport);
+        assert_eq!(visibility("inner"), Visibility::Internal);
+        assert_eq!(visibility("Store"), Visibility::Exported);
+        assert_eq!(visibility("Store.load"), Visibility::Internal);
+    }
+
+    #[test]
+    fn test_commonjs_exports() {
+        let adapter = TypeScriptAdapter::new_javascript().unwrap();
+        let source = r#"
+function parse() {}
+exports.format = function () {};
+module.exports = { parse, version: 1 };
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.js"), source)
+            .unwrap();
+
+        let exports: Vec<(&str, Option<&str>)> = module
+            .exports
+            .iter()
+            .map(|e| (e.name.as_str(), e.local.as_deref()))
+            .collect();
+        assert_eq!(
+            exports,
+            vec![
+                ("format", Some("exports.format")),
+                ("default", None),
+                ("parse", Some("parse")),
+                ("version", None),
+            ]
+        );
+
+        let visibility = |name: &str| {
+            module
+                .symbols
+                .iter()
+                .find(|s| s.name == name)
+                .unwrap()
+                .visibility
+        };
+        assert_eq!(visibility("parse"), Visibility::Exported);
+        assert_eq!(visibility("exports.format"), Visibility::Exported);
+        assert!(!module.dynamic_exports);
+    }
+
+    #[test]
+    fn test_commonjs_export_count() {
+        let adapter = TypeScriptAdapter::new_javascript().unwrap();
+        let export_count = |source: &str| {
+            let module = adapter
+                .parse(std::path::Path::new("test.js"), source)
+                .unwrap();
+            ApiSurfaceRule::default()
+                .check_module(&module)
+                .into_iter()
+                .find(|m| m.name == "export_count")
+                .unwrap()
+                .value
+        };
+
+        // The object's properties are the exports, the object itself is not one more
+        assert_eq!(export_count("module.exports = { parse, format };\n"), 2.0);
+        assert_eq!(export_count("module.exports = parse;\n"), 1.0);
+        assert_eq!(
+            export_count("export default { parse };\nexport const format = 1;\n"),
+            2.0
+        );
+    }
+
+    #[test]
+    fn test_dynamic_exports() {
+        let javascript = TypeScriptAdapter::new_javascript().unwrap();
+        let typescript = TypeScriptAdapter::new_typescript().unwrap();
+        let parse = |adapter: &TypeScriptAdapter, path: &str, source: &str| {
+            adapter.parse(std::path::Path::new(path), source).unwrap()
+        };
+
+        let api = parse(
+            &javascript,
+            "api.js",
+            "const api = { get() {} };\nmodule.exports = api;\n",
+        );
+        assert!(api.dynamic_exports);
+        let factory = parse(&javascript, "make.js", "module.exports = createApi();\n");
+        assert!(factory.dynamic_exports);
+        let legacy = parse(&typescript, "legacy.ts", "const api = {};\nexport = api;\n");
+        assert!(legacy.dynamic_exports);
+
+        let esm = parse(
+            &typescript,
+            "esm.ts",
+            "export default {};\nexport const a = 1;\n",
+        );
+        assert!(!esm.dynamic_exports);
+    }
+
+    #[test]
+    fn test_abstract_classes() {
+        let adapter = TypeScriptAdapter::new_typescript().unwrap();
+        let source = r#"
+export abstract class Repository {
+    abstract find(id: string): unknown;
+}
+
+export class MemoryRepository extends Repository {
+    find(id: string) {
+        return id;
+    }
+}
+"#;
+        let module = adapter
+            .parse(std::path::Path::new("test.ts"), source)
+            .unwrap();
+
+        let is_abstract = |name: &str| {
+            module
+                .symbols
+                .iter()
+                .find(|s| s.name == name)
+                .unwrap()
+                .is_abstract
+        };
+        assert!(is_abstract("Repository"));
+        assert!(!is_abstract("MemoryRepository"));
+    }
 }
@@ -1,5 +1,10 @@
 pub mod analyze;
+pub mod clusters;
+pub mod graph;
+pub mod impact;
 pub mod init;
+pub mod path;
+pub mod unreachable;
 
 use clap::{Parser, Subcommand};
 use std::path::PathBuf;
@@ -13,11 +18,11 @@ pub struct Cli {
     pub command: Option<Commands>,
 
     /// Path to file or directory to analyze (default: current directory)
-    #[arg(value_name = "PATH", global = true)]
+    #[arg(value_name = "PATH")]
     pub path: Option<PathBuf>,
 
-    /// Output format
-    #[arg(short, long, value_enum, global = true, default_value = "table")]
+    /// Output format (subcommands define their own)
+    #[arg(short, long, value_enum, default_value = "table")]
     pub format: OutputFormat,
 
     /// Write output to file
@@ -43,6 +48,10 @@ pub struct Cli {
     /// Include normally ignored directories (node_modules, dist, etc.)
     #[arg(long, global = true)]
     pub include_ignored: bool,
+
+    /// Exit with a failure status when an error-level issue is found
+    #[arg(long, global = true)]
+    pub strict: bool,
 }
 
 #[derive(Subcommand)]
@@ -50,6 +59,21 @@ pub enum Commands {
     /// Analyze code structure (default when no command specified)
     Analyze(AnalyzeArgs),
 
+    /// Export the module dependency graph
+    Graph(GraphArgs),
+
+    /// List the modules that depend on files, directly or transitively
+    Impact(ImpactArgs),
+
+    /// Show the import chains leading from one file to another
+    Path(PathArgs),
+
+    /// Group modules by their imports and flag files in the wrong directory
+    Clusters(ClustersArgs),
+
+    /// List the files no entry point imports, directly or transitively
+    Unreachable(UnreachableArgs),
+
     /// Generate default .codescope.toml config file
     Init(InitArgs),
 }
@@ -86,6 +110,162 @@ pub struct AnalyzeArgs {
     /// Include normally ignored directories
     #[arg(long)]
     pub include_ignored: bool,
+
+    /// Exit with a failure status when an error-level issue is found
+    #[arg(long)]
+    pub strict: bool,
+}
+
+#[derive(Parser)]
+pub struct GraphArgs {
+    /// Directory to scan (default: current directory)
+    pub path: Option<PathBuf>,
+
+    /// Graph format
+    #[arg(short, long, value_enum, default_value = "dot")]
+    pub format: GraphFormat,
+
+    /// Write the graph to a file instead of stdout
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Merge files into their parent directories
+    #[arg(long)]
+    pub collapse: bool,
+
+    /// Only show modules around this file
+    #[arg(long, value_name = "FILE")]
+    pub focus: Option<PathBuf>,
+
+    /// Import hops kept around --focus
+    #[arg(long, default_value_t = 1, requires = "focus")]
+    pub depth: usize,
+
+    /// Maximum depth for directory traversal
+    #[arg(long)]
+    pub max_depth: Option<usize>,
+
+    /// Custom config file path
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+
+    /// Include normally ignored directories
+    #[arg(long)]
+    pub include_ignored: bool,
+}
+
+#[derive(Parser)]
+pub struct ImpactArgs {
+    /// Changed files, e.g. from `git diff --name-only`
+    #[arg(required = true, value_name = "FILE")]
+    pub files: Vec<PathBuf>,
+
+    /// Project directory to search for importers
+    /// (default: nearest directory with package.json, tsconfig.json or .git)
+    #[arg(long)]
+    pub root: Option<PathBuf>,
+
+    /// Only follow imports up to this many hops
+    #[arg(long)]
+    pub max_distance: Option<usize>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "table")]
+    pub format: ReportFormat,
+
+    /// Write the JSON report to a file
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Include normally ignored directories
+    #[arg(long)]
+    pub include_ignored: bool,
+}
+
+#[derive(Parser)]
+pub struct PathArgs {
+    /// Importing file
+    pub from: PathBuf,
+
+    /// Imported file
+    pub to: PathBuf,
+
+    /// List every simple chain up to --max-hops, not just the shortest
+    #[arg(long)]
+    pub all: bool,
+
+    /// Longest chain listed with --all
+    #[arg(long, default_value_t = 6)]
+    pub max_hops: usize,
+
+    /// Most chains listed with --all
+    #[arg(long, default_value_t = 20)]
+    pub limit: usize,
+
+    /// Skip type-only imports, which are erased at compile time
+    #[arg(long)]
+    pub runtime_only: bool,
+
+    /// Project directory to scan
+    /// (default: nearest directory with package.json, tsconfig.json or .git)
+    #[arg(long)]
+    pub root: Option<PathBuf>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "table")]
+    pub format: ReportFormat,
+
+    /// Include normally ignored directories
+    #[arg(long)]
+    pub include_ignored: bool,
+}
+
+#[derive(Parser)]
+pub struct ClustersArgs {
+    /// Directory to scan (default: current directory)
+    pub path: Option<PathBuf>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "table")]
+    pub format: ReportFormat,
+
+    /// Write the JSON report to a file
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Include normally ignored directories
+    #[arg(long)]
+    pub include_ignored: bool,
+}
+
+#[derive(Parser)]
+pub struct UnreachableArgs {
+    /// Project directory to scan (default: current directory)
+    pub path: Option<PathBuf>,
+
+    /// Entry point in addition to `[reachability] entry_points` and package.json
+    #[arg(long = "entry", value_name = "FILE")]
+    pub entries: Vec<PathBuf>,
+
+    /// Output format
+    #[arg(short, long, value_enum, default_value = "table")]
+    pub format: ReportFormat,
+
+    /// Write the JSON report to a file
+    #[arg(short, long)]
+    pub output: Option<PathBuf>,
+
+    /// Custom config file path
+    #[arg(short, long)]
+    pub config: Option<PathBuf>,
+
+    /// Include normally ignored directories
+    #[arg(long)]
+    pub include_ignored: bool,
+
+    /// Exit with a failure status when an unreachable file is found
+    #[arg(long)]
+    pub strict: bool,
 }
 
 #[derive(Parser)]
@@ -105,6 +285,25 @@ pub enum OutputFormat {
     Md,
 }
 
+/// Formats for reports that have no Markdown rendering
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum ReportFormat {
+    Table,
+    Json,
+}
+
+#[derive(clap::ValueEnum, Clone, Debug)]
+pub enum GraphFormat {
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart, for Markdown docs
+    Mermaid,
+    /// GraphML, for yEd and Gephi
+    Graphml,
+    /// Cytoscape.js elements JSON
+    Cytoscape,
+}
+
 #[derive(clap::ValueEnum, Clone, Debug)]
 pub enum SortBy {
     Loc,
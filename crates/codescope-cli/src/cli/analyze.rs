@@ -1,12 +1,21 @@
 use crate::cli::{AnalyzeArgs, OutputFormat};
 use crate::output;
+use crate::pipeline;
 use anyhow::Result;
-use codescope_adapters::typescript::TypeScriptAdapter;
-use codescope_adapters::LanguageAdapter;
-use codescope_core::types::{ModuleIR, Severity};
-use codescope_core::Config;
+use codescope_adapters::AdapterRegistry;
+use codescope_core::aggregate::aggregate_directory;
+use codescope_core::centrality::{assign_risk_scores, risk_hotspots};
+use codescope_core::components::{component_metrics, Components};
+use codescope_core::graph::DependencyGraph;
+use codescope_core::resolve::normalize;
+use codescope_core::types::{Cycle, DirectoryReport, FileMetrics, ModuleIR, RealEdge, Severity};
+use codescope_core::{Config, ImportResolver};
+use std::collections::HashSet;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+
+/// Number of risk hotspots listed in the directory summary
+const MAX_HOTSPOTS: usize = 10;
 
 pub fn run(args: AnalyzeArgs) -> Result<()> {
     // Determine the path to analyze (from args or default to current dir)
@@ -35,12 +44,15 @@ fn analyze_file(path: &PathBuf, args: &AnalyzeArgs) -> Result<()> {
             }
         })
     });
-    let config = Config::load_or_default(config_path.as_deref());
+    let config = Config::load_or_default(config_path.as_deref())?;
     let registry = config.to_rule_registry();
 
-    // Parse the file
-    let adapter = TypeScriptAdapter::new_typescript()?;
-    let mut module = adapter.parse(path, &source)?;
+    // Parse the file with the grammar matching its extension
+    let adapters = AdapterRegistry::with_defaults()?;
+    let mut module = adapters.parse(path, &source)?;
+
+    // Point import edges at the files they load
+    ImportResolver::new().resolve_module(&mut module);
 
     // Apply quality rules
     let module_metrics = registry.check_module(&module);
@@ -86,14 +98,12 @@ fn analyze_file(path: &PathBuf, args: &AnalyzeArgs) -> Result<()> {
         }
     }
 
-    Ok(())
+    check_strict(std::slice::from_ref(&module), args)
 }
 
-fn analyze_directory(path: &PathBuf, args: &AnalyzeArgs) -> Result<()> {
-    use std::fs;
+fn analyze_directory(path: &Path, args: &AnalyzeArgs) -> Result<()> {
     use std::sync::atomic::{AtomicBool, Ordering};
     use std::sync::Arc;
-    use walkdir::WalkDir;
 
     // Setup Ctrl+C handler
     let interrupted = Arc::new(AtomicBool::new(false));
@@ -105,141 +115,160 @@ fn analyze_directory(path: &PathBuf, args: &AnalyzeArgs) -> Result<()> {
     }).expect("Error setting Ctrl-C handler");
 
     // Collect TypeScript/JavaScript files
-    let max_depth = args.max_depth.unwrap_or(usize::MAX);
-    let mut ts_files = Vec::new();
-
-    // Default ignore patterns
-    let ignore_dirs = [
-        "node_modules",
-        "dist",
-        "build",
-        ".git",
-        ".vite",
-        "deps",
-        "deps_temp",
-        "vendor",
-        "target",
-        ".next",
-        ".nuxt",
-        "out",
-        "coverage",
-        ".cache",
-    ];
-
-    let include_ignored = args.include_ignored;
-
-    for entry in WalkDir::new(path)
-        .max_depth(max_depth)
-        .follow_links(false)
-        .into_iter()
-        .filter_entry(move |e| {
-            // Skip ignored directories unless --include-ignored is set
-            if include_ignored {
-                true
-            } else if e.file_type().is_dir() {
-                let dir_name = e.file_name().to_string_lossy();
-                !ignore_dirs.iter().any(|&ignored| dir_name == ignored || dir_name.starts_with('.'))
-            } else {
-                true
-            }
-        })
-        .filter_map(|e| e.ok())
-    {
-        let path = entry.path();
-        if path.is_file() {
-            if let Some(ext) = path.extension() {
-                let ext_str = ext.to_string_lossy();
-                if matches!(ext_str.as_ref(), "ts" | "tsx" | "js" | "jsx") && !path.to_string_lossy().ends_with(".d.ts") {
-                    ts_files.push(path.to_path_buf());
-                }
-            }
-        }
-    }
+    let adapters = AdapterRegistry::with_defaults()?;
+    let ts_files = pipeline::collect_files(path, &adapters, args.max_depth, args.include_ignored);
 
     if ts_files.is_empty() {
         println!("No TypeScript/JavaScript files found in {}", path.display());
         return Ok(());
     }
 
+    // Files elsewhere in the project are parsed for context only: they supply incoming edges
+    let targets: HashSet<PathBuf> = ts_files.iter().map(|f| normalize(f)).collect();
+    let context_files: Vec<PathBuf> = pipeline::project_root(path)
+        .filter(|root| normalize(root) != normalize(path))
+        .map(|root| pipeline::collect_files(&root, &adapters, None, args.include_ignored))
+        .unwrap_or_default()
+        .into_iter()
+        .filter(|f| !targets.contains(&normalize(f)))
+        .collect();
+
     println!("Found {} TypeScript/JavaScript files", ts_files.len());
+    if !context_files.is_empty() {
+        println!(
+            "Reading {} more project files for incoming imports",
+            context_files.len()
+        );
+    }
     println!("Press Ctrl+C to stop and show results\n");
 
     // Load configuration
-    let config_path = args.config.clone().or_else(|| {
-        let config = path.join(".codescope.toml");
-        if config.exists() {
-            Some(config)
-        } else {
-            None
-        }
-    });
-    let config = Config::load_or_default(config_path.as_deref());
+    let config = pipeline::load_config(path, args.config.as_deref())?;
     let registry = config.to_rule_registry();
+    let resolver = ImportResolver::new();
 
-    // Analyze each file with streaming output
-    let adapter = TypeScriptAdapter::new_typescript()?;
+    // Parse every file first: fan-in needs the whole dependency graph
     let mut all_modules = Vec::new();
+    let mut target_count = 0;
     let mut error_count = 0;
-    let mut files_with_issues_count = 0;
+    let total = ts_files.len() + context_files.len();
 
-    for (i, file_path) in ts_files.iter().enumerate() {
+    for (i, file_path) in ts_files.iter().chain(&context_files).enumerate() {
         // Check for interruption
         if interrupted.load(Ordering::SeqCst) {
             break;
         }
 
-        eprint!("\r[{}/{}] Analyzing...", i + 1, ts_files.len());
-
-        match fs::read_to_string(file_path) {
-            Ok(source) => {
-                match adapter.parse(file_path, &source) {
-                    Ok(mut module) => {
-                        // Apply quality rules
-                        let module_metrics = registry.check_module(&module);
-                        module.metrics = module_metrics;
-
-                        // Apply symbol rules
-                        for symbol in &mut module.symbols {
-                            let symbol_metrics = registry.check_symbol(symbol);
-                            symbol.metrics = symbol_metrics;
-                        }
-
-                        // Check if file has issues
-                        let has_issues = module.metrics.iter().any(|m| {
-                            matches!(m.severity, Severity::Warning | Severity::Error)
-                        });
-
-                        if has_issues {
-                            files_with_issues_count += 1;
-
-                            // Clear progress line
-                            eprint!("\r{}\r", " ".repeat(50));
-
-                            // Print file with issues immediately
-                            print_file_issues(&module);
-                        }
-
-                        all_modules.push(module);
-                    }
-                    Err(_) => {
-                        error_count += 1;
-                    }
+        eprint!("\r[{}/{}] Analyzing...", i + 1, total);
+
+        let is_target = i < ts_files.len();
+        match pipeline::parse_file(&adapters, &resolver, file_path) {
+            Ok(module) => {
+                all_modules.push(module);
+                if is_target {
+                    target_count += 1;
                 }
             }
-            Err(_) => {
-                error_count += 1;
-            }
+            Err(_) if is_target => error_count += 1,
+            Err(_) => {}
+        }
+    }
+
+    // Link modules through their resolved imports
+    let graph = DependencyGraph::from_modules(&mut all_modules);
+    let mut cycles = graph.cycles();
+
+    pipeline::apply_rules(&registry, &graph, &mut all_modules);
+    assign_risk_scores(&mut all_modules);
+
+    // Roll the edges up to the directory, then keep only its own modules in the report
+    let mut directory = aggregate_directory(path, &all_modules);
+    let components = config
+        .architecture
+        .components(config.root.as_deref().unwrap_or(path))
+        .unwrap_or(Components::Directories);
+    directory.components = component_metrics(&graph, &all_modules, &components)
+        .into_iter()
+        .filter(|c| match components {
+            Components::Directories => normalize(Path::new(&c.name)).starts_with(normalize(path)),
+            Components::Layers(_) => true,
+        })
+        .collect();
+    all_modules.truncate(target_count);
+    cycles.retain(|cycle| {
+        cycle
+            .modules
+            .iter()
+            .any(|m| normalize(Path::new(m)).starts_with(normalize(path)))
+    });
+    let barrel_imports: Vec<RealEdge> = graph
+        .real_edges()
+        .iter()
+        .filter(|edge| normalize(Path::new(&edge.source)).starts_with(normalize(path)))
+        .cloned()
+        .collect();
+
+    // Clear progress line
+    eprint!("\r{}\r", " ".repeat(50));
+
+    let mut files_with_issues_count = 0;
+    for module in &all_modules {
+        // Check if file has issues
+        let has_issues = module
+            .metrics
+            .iter()
+            .any(|m| matches!(m.severity, Severity::Warning | Severity::Error));
+
+        if has_issues {
+            files_with_issues_count += 1;
+            print_file_issues(module);
         }
     }
 
     eprintln!("\r{}\r", " ".repeat(50));
     eprintln!("✓ Analyzed {} files ({} with issues, {} errors)",
         all_modules.len(), files_with_issues_count, error_count);
+    eprintln!("✓ Linked {} internal imports", graph.edge_count());
     println!();
 
     // Show summary
-    print_directory_summary(&all_modules, args)?;
+    print_cycles(&cycles);
+    output::print_directory_table(&directory)?;
+    let summary = FileMetrics {
+        fan_in: directory.metrics.fan_in,
+        fan_out: directory.metrics.fan_out,
+        risk_hotspots: risk_hotspots(&all_modules, MAX_HOTSPOTS),
+    };
+    let report = DirectoryReport {
+        directory: Some(directory),
+        modules: all_modules,
+        cycles,
+        summary: Some(summary),
+        barrel_imports,
+    };
+    print_directory_summary(&report, args)?;
+
+    check_strict(&report.modules, args)
+}
 
+/// With `--strict`, fails the run when any module or symbol has an error-level issue
+fn check_strict(modules: &[ModuleIR], args: &AnalyzeArgs) -> Result<()> {
+    if !args.strict {
+        return Ok(());
+    }
+
+    let errors = modules
+        .iter()
+        .flat_map(|m| {
+            m.metrics
+                .iter()
+                .chain(m.symbols.iter().flat_map(|s| &s.metrics))
+        })
+        .filter(|metric| metric.severity == Severity::Error)
+        .count();
+    if errors > 0 {
+        anyhow::bail!("{} error-level issues found (--strict)", errors);
+    }
     Ok(())
 }
 
@@ -266,7 +295,68 @@ fn print_file_issues(module: &ModuleIR) {
     println!();
 }
 
-fn print_directory_summary(modules: &[ModuleIR], args: &AnalyzeArgs) -> Result<()> {
+fn print_cycles(cycles: &[Cycle]) {
+    if cycles.is_empty() {
+        return;
+    }
+
+    println!("🔁 Import cycles ({})", cycles.len());
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    for (i, cycle) in cycles.iter().enumerate() {
+        println!("  Cycle {} ({} modules)", i + 1, cycle.modules.len());
+        for step in &cycle.path {
+            let line = step
+                .span
+                .map(|span| format!(":{}", span.start_line))
+                .unwrap_or_default();
+            println!("    {}{} → {}", step.source, line, step.target);
+        }
+        let others = cycle.modules.len().saturating_sub(cycle.path.len());
+        if others > 0 {
+            println!("    (+{} more modules in this cycle group)", others);
+        }
+    }
+    println!();
+}
+
+fn print_risk_hotspots(report: &DirectoryReport) {
+    let hotspots = match &report.summary {
+        Some(summary) if !summary.risk_hotspots.is_empty() => &summary.risk_hotspots,
+        _ => return,
+    };
+
+    println!("🔥 Risk hotspots (centrality × complexity)");
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    println!(
+        "{:<50} {:<8} {:<10} {:<12}",
+        "Module", "Risk", "PageRank", "Betweenness"
+    );
+    println!("{}", "-".repeat(80));
+    for path in hotspots {
+        let Some(module) = report.modules.iter().find(|m| &m.path == path) else {
+            continue;
+        };
+        let metric = |name: &str| {
+            module
+                .metrics
+                .iter()
+                .find(|m| m.name == name)
+                .map(|m| m.value)
+                .unwrap_or(0.0)
+        };
+        println!(
+            "{:<50} {:<8.2} {:<10.2} {:<12.1}",
+            path,
+            metric("risk_score"),
+            metric("pagerank"),
+            metric("betweenness")
+        );
+    }
+    println!();
+}
+
+fn print_directory_summary(report: &DirectoryReport, args: &AnalyzeArgs) -> Result<()> {
+    let modules = &report.modules;
     let total_loc: u32 = modules.iter().map(|m| m.loc).sum();
     let total_files = modules.len();
     let avg_loc = if total_files > 0 { total_loc / total_files as u32 } else { 0 };
@@ -299,6 +389,12 @@ fn print_directory_summary(modules: &[ModuleIR], args: &AnalyzeArgs) -> Result<(
     println!("  Total files:        {}", total_files);
     println!("  Total LOC:          {}", total_loc);
     println!("  Average LOC/file:   {}", avg_loc);
+    if !report.cycles.is_empty() {
+        println!("  Import cycles:      {}", report.cycles.len());
+    }
+    if !report.barrel_imports.is_empty() {
+        println!("  Barrel imports:     {}", report.barrel_imports.len());
+    }
     println!("  Files with issues:  {} ({:.1}%)",
         files_with_issues.len(),
         if total_files > 0 { files_with_issues.len() as f64 / total_files as f64 * 100.0 } else { 0.0 }
@@ -309,12 +405,14 @@ fn print_directory_summary(modules: &[ModuleIR], args: &AnalyzeArgs) -> Result<(
     }
     println!();
 
+    print_risk_hotspots(report);
+
     // Output to file if requested
     if let Some(output_path) = &args.output {
         let format = args.format.clone().unwrap_or(OutputFormat::Json);
         match format {
             OutputFormat::Json => {
-                let json = serde_json::to_string_pretty(&modules)?;
+                let json = serde_json::to_string_pretty(report)?;
                 fs::write(output_path, json)?;
                 println!("\n💾 Full results saved to: {}", output_path.display());
             }
@@ -0,0 +1,85 @@
+use crate::cli::{ClustersArgs, ReportFormat};
+use crate::pipeline;
+use anyhow::Result;
+use codescope_core::clusters::detect_clusters;
+use codescope_core::types::ClusterReport;
+use std::fs;
+use std::path::PathBuf;
+
+pub fn run(args: ClustersArgs) -> Result<()> {
+    let path = args.path.clone().unwrap_or_else(|| PathBuf::from("."));
+    let (_, graph, error_count) = pipeline::load_graph(&path, args.include_ignored)?;
+    let report = detect_clusters(&graph);
+
+    if let Some(output_path) = &args.output {
+        fs::write(output_path, serde_json::to_string_pretty(&report)?)?;
+        eprintln!("💾 Cluster report written to: {}", output_path.display());
+    } else {
+        match args.format {
+            ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+            ReportFormat::Table => print_clusters(&report),
+        }
+    }
+    if error_count > 0 {
+        eprintln!("⚠ {} files could not be parsed", error_count);
+    }
+
+    Ok(())
+}
+
+fn print_clusters(report: &ClusterReport) {
+    println!("🧩 Import clusters ({})", report.clusters.len());
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    if report.clusters.is_empty() {
+        println!("  No modules import each other");
+        return;
+    }
+
+    for (i, cluster) in report.clusters.iter().enumerate() {
+        let others = cluster.directories.len() - 1;
+        let spread = if others > 0 {
+            format!(", +{} other directories", others)
+        } else {
+            String::new()
+        };
+        println!(
+            "  Cluster {} ({} modules, mostly {}{})",
+            i + 1,
+            cluster.modules.len(),
+            cluster.directory,
+            spread
+        );
+        for module in &cluster.modules {
+            println!("    {}", module);
+        }
+    }
+    println!();
+
+    if !report.misplaced.is_empty() {
+        println!(
+            "📦 Files in the wrong directory ({})",
+            report.misplaced.len()
+        );
+        println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+        println!(
+            "{:<50} {:<30} {:<10} {:<10}",
+            "Module", "Suggested directory", "Links", "Own links"
+        );
+        println!("{}", "-".repeat(100));
+        for module in &report.misplaced {
+            println!(
+                "{:<50} {:<30} {:<10} {:<10}",
+                module.path, module.suggested, module.suggested_links, module.own_links
+            );
+        }
+        println!();
+    }
+
+    println!("📊 Summary");
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    println!("  Cluster modularity:    {:.3}", report.modularity);
+    println!(
+        "  Directory modularity:  {:.3}",
+        report.directory_modularity
+    );
+}
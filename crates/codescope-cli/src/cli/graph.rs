@@ -0,0 +1,67 @@
+use crate::cli::{GraphArgs, GraphFormat};
+use crate::pipeline;
+use anyhow::Result;
+use codescope_adapters::AdapterRegistry;
+use codescope_core::graph::DependencyGraph;
+use codescope_core::graph_view::{GraphView, GraphViewOptions};
+use codescope_core::ImportResolver;
+use std::fs;
+use std::path::PathBuf;
+
+pub fn run(args: GraphArgs) -> Result<()> {
+    let path = args.path.clone().unwrap_or_else(|| PathBuf::from("."));
+    if !path.is_dir() {
+        anyhow::bail!(
+            "Not a directory: {}\nUse --focus <FILE> to center the graph on a file",
+            path.display()
+        );
+    }
+
+    let adapters = AdapterRegistry::with_defaults()?;
+    let files = pipeline::collect_files(&path, &adapters, args.max_depth, args.include_ignored);
+    if files.is_empty() {
+        anyhow::bail!("No TypeScript/JavaScript files found in {}", path.display());
+    }
+
+    let config = pipeline::load_config(&path, args.config.as_deref())?;
+    let registry = config.to_rule_registry();
+    let resolver = ImportResolver::new();
+
+    let (mut modules, error_count) = pipeline::parse_files(&adapters, &resolver, &files);
+
+    // Node colors come from the rule results
+    let graph = DependencyGraph::from_modules(&mut modules);
+    pipeline::apply_rules(&registry, &graph, &mut modules);
+
+    let options = GraphViewOptions {
+        collapse: args.collapse,
+        focus: args.focus.clone(),
+        depth: Some(args.depth),
+        root: Some(path.clone()),
+    };
+    let view = GraphView::build(&graph, &modules, &options)?;
+
+    let content = match args.format {
+        GraphFormat::Dot => view.to_dot(),
+        GraphFormat::Mermaid => view.to_mermaid(),
+        GraphFormat::Graphml => view.to_graphml(),
+        GraphFormat::Cytoscape => view.to_cytoscape()?,
+    };
+
+    if let Some(output_path) = &args.output {
+        fs::write(output_path, content)?;
+        eprintln!(
+            "✓ Graph with {} nodes and {} edges written to: {}",
+            view.nodes.len(),
+            view.edges.len(),
+            output_path.display()
+        );
+    } else {
+        print!("{}", content);
+    }
+    if error_count > 0 {
+        eprintln!("⚠ {} files could not be parsed", error_count);
+    }
+
+    Ok(())
+}
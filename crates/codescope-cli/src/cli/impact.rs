@@ -0,0 +1,89 @@
+use crate::cli::{ImpactArgs, ReportFormat};
+use crate::pipeline;
+use anyhow::Result;
+use codescope_core::types::{AffectedModule, ImpactReport};
+use std::collections::HashMap;
+use std::fs;
+
+pub fn run(args: ImpactArgs) -> Result<()> {
+    let root = pipeline::scan_root(args.root.as_deref(), &args.files[0]);
+    let (modules, graph, error_count) = pipeline::load_graph(&root, args.include_ignored)?;
+
+    // Files in a diff may be deleted or not source code: skip them with a note
+    let mut targets = Vec::new();
+    for file in &args.files {
+        match pipeline::find_module(&graph, file) {
+            Some(path) => targets.push(path),
+            None => eprintln!(
+                "⚠ Not a module under {}: {}",
+                root.display(),
+                file.display()
+            ),
+        }
+    }
+    if targets.is_empty() {
+        anyhow::bail!(
+            "None of the given files is a module under {}",
+            root.display()
+        );
+    }
+
+    let loc: HashMap<&str, u32> = modules.iter().map(|m| (m.path.as_str(), m.loc)).collect();
+    let affected: Vec<AffectedModule> = graph
+        .dependents(&targets)
+        .into_iter()
+        .filter(|(_, distance)| args.max_distance.map_or(true, |max| *distance <= max))
+        .map(|(path, distance)| AffectedModule {
+            loc: loc.get(path.as_str()).copied().unwrap_or(0),
+            path,
+            distance: distance as u32,
+        })
+        .collect();
+
+    let report = ImpactReport {
+        targets: targets.iter().map(|t| t.to_string()).collect(),
+        files: affected.len() as u32,
+        loc: affected.iter().map(|m| m.loc).sum(),
+        affected,
+    };
+
+    if let Some(output_path) = &args.output {
+        fs::write(output_path, serde_json::to_string_pretty(&report)?)?;
+        eprintln!("💾 Impact report written to: {}", output_path.display());
+    } else {
+        match args.format {
+            ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+            ReportFormat::Table => print_impact(&report),
+        }
+    }
+    if error_count > 0 {
+        eprintln!("⚠ {} files could not be parsed", error_count);
+    }
+
+    Ok(())
+}
+
+fn print_impact(report: &ImpactReport) {
+    println!("💥 Impact of {}", report.targets.join(", "));
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    if report.affected.is_empty() {
+        println!("  No module imports these files");
+        return;
+    }
+
+    println!("{:<10} {:<50} {:<6}", "Distance", "Module", "LOC");
+    println!("{}", "-".repeat(68));
+    for module in &report.affected {
+        println!(
+            "{:<10} {:<50} {:<6}",
+            module.distance, module.path, module.loc
+        );
+    }
+    println!();
+
+    let direct = report.affected.iter().filter(|m| m.distance == 1).count();
+    println!("📊 Summary");
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    println!("  Affected files:     {} ({} direct)", report.files, direct);
+    println!("  Affected LOC:       {}", report.loc);
+}
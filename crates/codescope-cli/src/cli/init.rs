@@ -19,11 +19,42 @@ max_types_per_file = 30         # Maximum type definitions per file
 max_fan_out = 7                 # Maximum number of dependencies
 max_imports = 15                # Maximum number of import statements
 
+# Public API thresholds
+max_exports = 20                # Maximum number of exported names
+max_barrel_reexports = 10       # Maximum re-exports in a barrel (index) module
+
+# Architecture
+stable_dependencies = false     # Flag imports into less stable components
+centrality = true               # PageRank and betweenness for risk hotspots
+unused_exports = true           # Exports no other module imports
+broken_imports = true           # Imports of missing files or missing exports
+
 # Severity levels for rule violations
 [rules.severity]
 max_file_loc = "Warning"        # Options: "Info", "Warning", "Error"
 max_function_loc = "Warning"
 max_fan_out = "Warning"
+max_exports = "Warning"
+max_barrel_reexports = "Warning"
+no_cycles = "Warning"           # Modules in a runtime import cycle
+layers = "Error"                # Imports against the [architecture] layer order
+stable_dependencies = "Warning"
+unused_exports = "Warning"
+broken_imports = "Error"
+
+# Architecture layers, top to bottom: a layer may import itself and the layers below it
+# [architecture]
+# layers = [
+#     { name = "ui", paths = ["src/ui/**", "src/pages/**"] },
+#     { name = "domain", paths = ["src/domain/**"] },
+#     { name = "infra", paths = ["src/infra/**"] },
+# ]
+# forbidden = [{ from = "ui", to = "infra" }]
+
+# Extra entry points for `codescope unreachable` and public API for unused exports
+# (package.json main/bin/exports are included)
+# [reachability]
+# entry_points = ["src/main.ts", "scripts/*.ts"]
 "#;
 
 pub fn run(args: InitArgs) -> Result<()> {
@@ -0,0 +1,75 @@
+use crate::cli::{PathArgs, ReportFormat};
+use crate::pipeline;
+use anyhow::Result;
+use codescope_core::types::{CycleStep, PathReport};
+
+pub fn run(args: PathArgs) -> Result<()> {
+    let root = pipeline::scan_root(args.root.as_deref(), &args.from);
+    let (_, graph, error_count) = pipeline::load_graph(&root, args.include_ignored)?;
+    if error_count > 0 {
+        eprintln!("⚠ {} files could not be parsed", error_count);
+    }
+
+    let find = |file| {
+        pipeline::find_module(&graph, file).ok_or_else(|| {
+            anyhow::anyhow!("Not a module under {}: {}", root.display(), file.display())
+        })
+    };
+    let (from, to) = (find(&args.from)?, find(&args.to)?);
+    if from == to {
+        anyhow::bail!("Both ends are the same module: {}", from);
+    }
+
+    let paths = if args.all {
+        graph.all_paths(from, to, args.max_hops, args.limit, args.runtime_only)
+    } else {
+        graph
+            .shortest_path(from, to, args.runtime_only)
+            .into_iter()
+            .collect()
+    };
+    if paths.is_empty() {
+        let bound = if args.all {
+            format!(" within {} hops", args.max_hops)
+        } else {
+            String::new()
+        };
+        anyhow::bail!("No import path from {} to {}{}", from, to, bound);
+    }
+
+    let report = PathReport {
+        from: from.to_string(),
+        to: to.to_string(),
+        paths,
+    };
+    match args.format {
+        ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        ReportFormat::Table => print_paths(&report),
+    }
+
+    Ok(())
+}
+
+fn print_paths(report: &PathReport) {
+    println!("🔗 {} → {}", report.from, report.to);
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    for (i, path) in report.paths.iter().enumerate() {
+        if report.paths.len() > 1 {
+            println!("  Path {} ({} hops)", i + 1, path.len());
+        } else {
+            println!("  {} hops", path.len());
+        }
+        for step in path {
+            println!("    {}", format_step(step));
+        }
+    }
+}
+
+/// `source:line → target`, the line being the import in `source`
+fn format_step(step: &CycleStep) -> String {
+    let line = step
+        .span
+        .map(|span| format!(":{}", span.start_line))
+        .unwrap_or_default();
+    format!("{}{} → {}", step.source, line, step.target)
+}
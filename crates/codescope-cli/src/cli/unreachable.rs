@@ -0,0 +1,97 @@
+use crate::cli::{ReportFormat, UnreachableArgs};
+use crate::pipeline;
+use anyhow::Result;
+use codescope_core::reachability::unreachable_modules;
+use codescope_core::types::{UnreachableModule, UnreachableReport};
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+pub fn run(args: UnreachableArgs) -> Result<()> {
+    let path = args.path.clone().unwrap_or_else(|| PathBuf::from("."));
+    let config = pipeline::load_config(&path, args.config.as_deref())?;
+    let (modules, graph, error_count) = pipeline::load_graph(&path, args.include_ignored)?;
+
+    let root = config.root.clone().unwrap_or_else(|| path.clone());
+    let mut entry_points = config.reachability.entry_points()?.find(&graph, &root);
+    for file in &args.entries {
+        let entry = pipeline::find_module(&graph, file).ok_or_else(|| {
+            anyhow::anyhow!("Not a module under {}: {}", path.display(), file.display())
+        })?;
+        if !entry_points.contains(&entry) {
+            entry_points.push(entry);
+        }
+    }
+    if entry_points.is_empty() {
+        anyhow::bail!(
+            "No entry points under {}: set [reachability] entry_points in .codescope.toml, \
+             main/bin/exports in package.json, or pass --entry",
+            path.display()
+        );
+    }
+
+    let loc: HashMap<&str, u32> = modules.iter().map(|m| (m.path.as_str(), m.loc)).collect();
+    let unreachable: Vec<UnreachableModule> = unreachable_modules(&graph, &entry_points)
+        .into_iter()
+        .map(|path| UnreachableModule {
+            path: path.to_string(),
+            loc: loc.get(path).copied().unwrap_or(0),
+        })
+        .collect();
+
+    let report = UnreachableReport {
+        entry_points: entry_points.iter().map(|e| e.to_string()).collect(),
+        files: unreachable.len() as u32,
+        loc: unreachable.iter().map(|m| m.loc).sum(),
+        unreachable,
+    };
+
+    if let Some(output_path) = &args.output {
+        fs::write(output_path, serde_json::to_string_pretty(&report)?)?;
+        eprintln!(
+            "💾 Unreachable report written to: {}",
+            output_path.display()
+        );
+    } else {
+        match args.format {
+            ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+            ReportFormat::Table => print_unreachable(&report),
+        }
+    }
+    if error_count > 0 {
+        eprintln!("⚠ {} files could not be parsed", error_count);
+    }
+
+    if args.strict && report.files > 0 {
+        anyhow::bail!("{} unreachable files found (--strict)", report.files);
+    }
+    Ok(())
+}
+
+fn print_unreachable(report: &UnreachableReport) {
+    println!("🚪 Entry points ({})", report.entry_points.len());
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    for entry in &report.entry_points {
+        println!("  {}", entry);
+    }
+    println!();
+
+    println!("🪦 Unreachable files ({})", report.files);
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    if report.unreachable.is_empty() {
+        println!("  Every file is reachable from an entry point");
+        return;
+    }
+
+    println!("{:<60} {:<6}", "Module", "LOC");
+    println!("{}", "-".repeat(68));
+    for module in &report.unreachable {
+        println!("{:<60} {:<6}", module.path, module.loc);
+    }
+    println!();
+
+    println!("📊 Summary");
+    println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━");
+    println!("  Unreachable files:  {}", report.files);
+    println!("  Unreachable LOC:    {}", report.loc);
+}
@@ -1,5 +1,6 @@
 mod cli;
 mod output;
+mod pipeline;
 
 use anyhow::Result;
 use clap::Parser;
@@ -12,6 +13,21 @@ fn main() -> Result<()> {
         Some(Commands::Analyze(args)) => {
             cli::analyze::run(args)?;
         }
+        Some(Commands::Graph(args)) => {
+            cli::graph::run(args)?;
+        }
+        Some(Commands::Impact(args)) => {
+            cli::impact::run(args)?;
+        }
+        Some(Commands::Path(args)) => {
+            cli::path::run(args)?;
+        }
+        Some(Commands::Clusters(args)) => {
+            cli::clusters::run(args)?;
+        }
+        Some(Commands::Unreachable(args)) => {
+            cli::unreachable::run(args)?;
+        }
         Some(Commands::Init(args)) => {
             cli::init::run(args)?;
         }
@@ -26,6 +42,7 @@ fn main() -> Result<()> {
                 sort: Some(cli.sort),
                 config: cli.config,
                 include_ignored: cli.include_ignored,
+                strict: cli.strict,
             };
             cli::analyze::run(args)?;
         }
@@ -1,6 +1,5 @@
 use anyhow::Result;
-use codescope_core::types::{ModuleIR, Severity};
-use serde_json;
+use codescope_core::types::{DepEdge, DirectoryIR, ModuleIR, Severity, Span};
 
 pub fn print_module_json(module: &ModuleIR) -> Result<()> {
     let json = serde_json::to_string_pretty(module)?;
@@ -60,8 +59,11 @@ pub fn print_module_table(module: &ModuleIR, _no_suggest: bool) -> Result<()> {
     // Print structure with issues
     if !module.symbols.is_empty() {
         println!("[Structure]");
-        println!("{:<12} {:<30} {:<6} {:<30}", "Type", "Name", "LOC", "Issues");
-        println!("{}", "-".repeat(80));
+        println!(
+            "{:<12} {:<30} {:<6} {:<8} {:<30}",
+            "Type", "Name", "LOC", "Line", "Issues"
+        );
+        println!("{}", "-".repeat(89));
 
         for symbol in &module.symbols {
             let issues: Vec<String> = symbol
@@ -85,10 +87,11 @@ pub fn print_module_table(module: &ModuleIR, _no_suggest: bool) -> Result<()> {
             };
 
             println!(
-                "{:<12} {:<30} {:<6} {:<30}",
+                "{:<12} {:<30} {:<6} {:<8} {:<30}",
                 format!("{:?}", symbol.kind).to_lowercase(),
                 symbol.name,
                 symbol.loc,
+                format_location(symbol.span.as_ref()),
                 issues_str
             );
         }
@@ -98,16 +101,20 @@ pub fn print_module_table(module: &ModuleIR, _no_suggest: bool) -> Result<()> {
     // Print outgoing dependencies
     if !module.outgoing.is_empty() {
         println!("[Outgoing]");
-        println!("{:<30} {:<12} {:<8}", "Target", "Relation", "Strength");
-        println!("{}", "-".repeat(55));
+        println!(
+            "{:<30} {:<22} {:<8} {:<8}",
+            "Target", "Relation", "Strength", "Line"
+        );
+        println!("{}", "-".repeat(74));
 
         for dep in &module.outgoing {
             if let Some(target) = &dep.target {
                 println!(
-                    "{:<30} {:<12} {:<8.2}",
+                    "{:<30} {:<22} {:<8.2} {:<8}",
                     target,
-                    format!("{:?}", dep.relation).to_lowercase(),
-                    dep.strength
+                    dep.relation_label(),
+                    dep.strength,
+                    format_location(dep.span.as_ref())
                 );
             }
         }
@@ -117,16 +124,20 @@ pub fn print_module_table(module: &ModuleIR, _no_suggest: bool) -> Result<()> {
     // Print incoming dependencies
     if !module.incoming.is_empty() {
         println!("[Incoming]");
-        println!("{:<30} {:<12} {:<8}", "Source", "Relation", "Strength");
-        println!("{}", "-".repeat(55));
+        println!(
+            "{:<30} {:<22} {:<8} {:<8}",
+            "Source", "Relation", "Strength", "Line"
+        );
+        println!("{}", "-".repeat(74));
 
         for dep in &module.incoming {
             if let Some(source) = &dep.source {
                 println!(
-                    "{:<30} {:<12} {:<8.2}",
+                    "{:<30} {:<22} {:<8.2} {:<8}",
                     source,
-                    format!("{:?}", dep.relation).to_lowercase(),
-                    dep.strength
+                    dep.relation_label(),
+                    dep.strength,
+                    format_location(dep.span.as_ref())
                 );
             }
         }
@@ -136,11 +147,141 @@ pub fn print_module_table(module: &ModuleIR, _no_suggest: bool) -> Result<()> {
     Ok(())
 }
 
+pub fn print_directory_table(directory: &DirectoryIR) -> Result<()> {
+    println!(
+        "Target: {}/ ({} files, {} LOC, fan-out {}, fan-in {})",
+        directory.path.trim_end_matches('/'),
+        directory.metrics.files,
+        directory.metrics.loc,
+        directory.metrics.fan_out,
+        directory.metrics.fan_in
+    );
+    println!();
+
+    if !directory.children.is_empty() {
+        println!("[Children]");
+        println!(
+            "{:<10} {:<30} {:<6} {:<30}",
+            "Type", "Name", "LOC", "Issues"
+        );
+        println!("{}", "-".repeat(79));
+
+        for child in &directory.children {
+            let issues: Vec<String> = child
+                .metrics
+                .iter()
+                .map(|m| m.name.replace('_', " "))
+                .collect();
+            println!(
+                "{:<10} {:<30} {:<6} {:<30}",
+                child.entry_type,
+                child.name,
+                child.loc,
+                issues.join(", ")
+            );
+        }
+        println!();
+    }
+
+    if !directory.components.is_empty() {
+        println!("[Components]");
+        println!(
+            "{:<30} {:<6} {:<5} {:<5} {:<6} {:<6} {:<6}",
+            "Component", "Files", "Ca", "Ce", "I", "A", "D"
+        );
+        println!("{}", "-".repeat(70));
+
+        for component in &directory.components {
+            println!(
+                "{:<30} {:<6} {:<5} {:<5} {:<6.2} {:<6.2} {:<6.2}",
+                component.name,
+                component.files,
+                component.afferent,
+                component.efferent,
+                component.instability,
+                component.abstractness,
+                component.distance
+            );
+        }
+        println!();
+    }
+
+    print_aggregated_edges(
+        "[Outgoing (aggregated)]",
+        "Target",
+        &directory.outgoing,
+        |dep| dep.target.as_deref(),
+    );
+    print_aggregated_edges(
+        "[Incoming (aggregated)]",
+        "Source",
+        &directory.incoming,
+        |dep| dep.source.as_deref(),
+    );
+
+    Ok(())
+}
+
+fn print_aggregated_edges(
+    title: &str,
+    endpoint: &str,
+    edges: &[DepEdge],
+    name: impl Fn(&DepEdge) -> Option<&str>,
+) {
+    if edges.is_empty() {
+        return;
+    }
+
+    println!("{}", title);
+    println!(
+        "{:<30} {:<22} {:<6} {:<8}",
+        endpoint, "Relation", "Files", "Strength"
+    );
+    println!("{}", "-".repeat(69));
+
+    for dep in edges {
+        if let Some(name) = name(dep) {
+            println!(
+                "{:<30} {:<22} {:<6} {:<8.2}",
+                name,
+                dep.relation_label(),
+                dep.files.unwrap_or(1),
+                dep.strength
+            );
+        }
+    }
+    println!();
+}
+
+/// `line:column` of the start of a span, for jumping from a report to the code
+fn format_location(span: Option<&Span>) -> String {
+    span.map(|s| format!("{}:{}", s.start_line, s.start_column))
+        .unwrap_or_else(|| "-".to_string())
+}
+
 fn metric_category(name: &str) -> &str {
     match name {
+        n if n.contains("export") => "API",
+        n if n.contains("layer") || n.contains("dependency") => "Architecture",
+        n if n.contains("pagerank") || n.contains("betweenness") || n.contains("risk") => {
+            "Centrality"
+        }
         n if n.contains("file") || n.contains("loc") || n.contains("comment") || n.contains("blank") => "Size",
-        n if n.contains("function") || n.contains("class") || n.contains("interface") || n.contains("type") => "Structure",
-        n if n.contains("fan") || n.contains("import") || n.contains("coupling") => "Coupling",
+        n if n.contains("function")
+            || n.contains("method")
+            || n.contains("class")
+            || n.contains("interface")
+            || n.contains("type") =>
+        {
+            "Structure"
+        }
+        n if n.contains("fan")
+            || n.contains("import")
+            || n.contains("cycle")
+            || n.contains("coupling") =>
+        {
+            "Coupling"
+        }
         _ => "Other",
     }
 }
@@ -185,8 +326,8 @@ pub fn format_module_markdown(module: &ModuleIR, _no_suggest: bool) -> Result<St
 
     if !module.symbols.is_empty() {
         output.push_str("## Structure\n\n");
-        output.push_str("| Type | Name | LOC | Issues |\n");
-        output.push_str("|------|------|-----|--------|\n");
+        output.push_str("| Type | Name | LOC | Line | Issues |\n");
+        output.push_str("|------|------|-----|------|--------|\n");
 
         for symbol in &module.symbols {
             let issues: Vec<String> = symbol
@@ -203,16 +344,55 @@ pub fn format_module_markdown(module: &ModuleIR, _no_suggest: bool) -> Result<St
             };
 
             output.push_str(&format!(
-                "| {} | {} | {} | {} |\n",
-                format!("{:?}", symbol.kind),
+                "| {:?} | {} | {} | {} | {} |\n",
+                symbol.kind,
                 symbol.name,
                 symbol.loc,
+                format_location(symbol.span.as_ref()),
                 issues_str
             ));
         }
         output.push('\n');
     }
 
+    if !module.outgoing.is_empty() {
+        output.push_str("## Outgoing\n\n");
+        output.push_str("| Target | Relation | Strength | Line |\n");
+        output.push_str("|--------|----------|----------|------|\n");
+
+        for dep in &module.outgoing {
+            if let Some(target) = &dep.target {
+                output.push_str(&format!(
+                    "| {} | {} | {:.2} | {} |\n",
+                    target,
+                    dep.relation_label(),
+                    dep.strength,
+                    format_location(dep.span.as_ref())
+                ));
+            }
+        }
+        output.push('\n');
+    }
+
+    if !module.incoming.is_empty() {
+        output.push_str("## Incoming\n\n");
+        output.push_str("| Source | Relation | Strength | Line |\n");
+        output.push_str("|--------|----------|----------|------|\n");
+
+        for dep in &module.incoming {
+            if let Some(source) = &dep.source {
+                output.push_str(&format!(
+                    "| {} | {} | {:.2} | {} |\n",
+                    source,
+                    dep.relation_label(),
+                    dep.strength,
+                    format_location(dep.span.as_ref())
+                ));
+            }
+        }
+        output.push('\n');
+    }
+
     Ok(output)
 }
 
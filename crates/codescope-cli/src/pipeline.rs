@@ -0,0 +1,192 @@
+use anyhow::Result;
+use codescope_adapters::AdapterRegistry;
+use codescope_core::graph::DependencyGraph;
+use codescope_core::rules::{GraphMetric, RuleRegistry};
+use codescope_core::types::{ModuleIR, QualityMetric};
+use codescope_core::{Config, ImportResolver};
+use std::collections::{BTreeMap, HashMap};
+use std::fs;
+use std::path::{Path, PathBuf};
+use walkdir::WalkDir;
+
+/// Directories skipped unless `--include-ignored` is set
+const IGNORED_DIRS: &[&str] = &[
+    "node_modules",
+    "dist",
+    "build",
+    ".git",
+    ".vite",
+    "deps",
+    "deps_temp",
+    "vendor",
+    "target",
+    ".next",
+    ".nuxt",
+    "out",
+    "coverage",
+    ".cache",
+];
+
+/// Source files under `root` that one of the adapters can parse, declaration files excluded
+pub fn collect_files(
+    root: &Path,
+    adapters: &AdapterRegistry,
+    max_depth: Option<usize>,
+    include_ignored: bool,
+) -> Vec<PathBuf> {
+    WalkDir::new(root)
+        .max_depth(max_depth.unwrap_or(usize::MAX))
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(move |e| {
+            // Skip ignored and hidden directories unless --include-ignored is set
+            if include_ignored || !e.file_type().is_dir() || e.depth() == 0 {
+                true
+            } else {
+                let dir_name = e.file_name().to_string_lossy();
+                !IGNORED_DIRS
+                    .iter()
+                    .any(|&ignored| dir_name == ignored || dir_name.starts_with('.'))
+            }
+        })
+        .filter_map(|e| e.ok())
+        .map(|entry| entry.into_path())
+        .filter(|path| {
+            path.is_file() && adapters.supports(path) && !path.to_string_lossy().ends_with(".d.ts")
+        })
+        .collect()
+}
+
+/// `--config` when given, else `.codescope.toml` in the analyzed directory
+pub fn load_config(dir: &Path, explicit: Option<&Path>) -> Result<Config> {
+    let config_path = explicit.map(Path::to_path_buf).or_else(|| {
+        let config = dir.join(".codescope.toml");
+        if config.exists() {
+            Some(config)
+        } else {
+            None
+        }
+    });
+    let mut config = Config::load_or_default(config_path.as_deref())?;
+    config.root = Some(project_root(dir).unwrap_or_else(|| dir.to_path_buf()));
+    Ok(config)
+}
+
+/// Reads and parses one file, with its imports resolved
+pub fn parse_file(
+    adapters: &AdapterRegistry,
+    resolver: &ImportResolver,
+    path: &Path,
+) -> Result<ModuleIR> {
+    let source = fs::read_to_string(path)?;
+    let mut module = adapters.parse(path, &source)?;
+    resolver.resolve_module(&mut module);
+    Ok(module)
+}
+
+/// Parses `files`, skipping the ones that fail; returns the modules and the failure count
+pub fn parse_files(
+    adapters: &AdapterRegistry,
+    resolver: &ImportResolver,
+    files: &[PathBuf],
+) -> (Vec<ModuleIR>, usize) {
+    let mut modules = Vec::new();
+    let mut error_count = 0;
+    for file in files {
+        match parse_file(adapters, resolver, file) {
+            Ok(module) => modules.push(module),
+            Err(_) => error_count += 1,
+        }
+    }
+    (modules, error_count)
+}
+
+/// Runs module, symbol and graph rules over linked modules
+pub fn apply_rules(registry: &RuleRegistry, graph: &DependencyGraph, modules: &mut [ModuleIR]) {
+    // Graph rules report per module or per symbol; merge them with the module and symbol rules
+    let mut graph_metrics: HashMap<String, Vec<GraphMetric>> = HashMap::new();
+    let mut all_graph_metrics = registry.check_graph(graph);
+    all_graph_metrics.extend(registry.check_modules(graph, modules));
+    for graph_metric in all_graph_metrics {
+        graph_metrics
+            .entry(graph_metric.path.clone())
+            .or_default()
+            .push(graph_metric);
+    }
+
+    for module in modules {
+        let mut module_metrics = registry.check_module(module);
+        let mut symbol_metrics: BTreeMap<String, Vec<QualityMetric>> = BTreeMap::new();
+        for graph_metric in graph_metrics.remove(&module.path).unwrap_or_default() {
+            match graph_metric.symbol {
+                Some(symbol) => symbol_metrics
+                    .entry(symbol)
+                    .or_default()
+                    .push(graph_metric.metric),
+                None => module_metrics.push(graph_metric.metric),
+            }
+        }
+
+        for symbol in &mut module.symbols {
+            symbol.metrics = registry.check_symbol(symbol);
+            if symbol.parent.is_none() {
+                symbol
+                    .metrics
+                    .extend(symbol_metrics.remove(&symbol.name).unwrap_or_default());
+            }
+        }
+        // Names without a symbol of their own, such as `export const X = 1`, keep their
+        // metrics on the module
+        module_metrics.extend(symbol_metrics.into_values().flatten());
+        module.metrics = module_metrics;
+    }
+}
+
+/// Files marking the top of a project: nearest ancestor holding one of them
+const PROJECT_MARKERS: &[&str] = &["package.json", "tsconfig.json", "jsconfig.json", ".git"];
+
+/// Nearest ancestor of `path` (itself included) that looks like a project root.
+/// Only the path as given is searched, so a relative path stops at the working directory.
+pub fn project_root(path: &Path) -> Option<PathBuf> {
+    path.ancestors().find_map(|ancestor| {
+        let dir = if ancestor.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            ancestor
+        };
+        PROJECT_MARKERS
+            .iter()
+            .any(|marker| dir.join(marker).exists())
+            .then(|| dir.to_path_buf())
+    })
+}
+
+/// `--root` when given, else the project around `file`, else the working directory
+pub fn scan_root(root: Option<&Path>, file: &Path) -> PathBuf {
+    root.map(Path::to_path_buf)
+        .or_else(|| project_root(file))
+        .unwrap_or_else(|| PathBuf::from("."))
+}
+
+/// Parses and links every source file under `root`; also returns the parse failure count
+pub fn load_graph(
+    root: &Path,
+    include_ignored: bool,
+) -> Result<(Vec<ModuleIR>, DependencyGraph, usize)> {
+    if !root.is_dir() {
+        anyhow::bail!("Not a directory: {}", root.display());
+    }
+    let adapters = AdapterRegistry::with_defaults()?;
+    let files = collect_files(root, &adapters, None, include_ignored);
+    let (mut modules, error_count) = parse_files(&adapters, &ImportResolver::new(), &files);
+    let graph = DependencyGraph::from_modules(&mut modules);
+    Ok((modules, graph, error_count))
+}
+
+/// The graph module for `file`, also trying it relative to the working directory
+pub fn find_module<'a>(graph: &'a DependencyGraph, file: &Path) -> Option<&'a str> {
+    graph.find(file).or_else(|| {
+        let absolute = std::env::current_dir().ok()?.join(file);
+        graph.find(&absolute)
+    })
+}
@@ -0,0 +1,164 @@
+use crate::resolve::normalize;
+use crate::types::{
+    ChildEntry, DepEdge, DepKind, DirectoryIR, DirectoryMetrics, ModuleIR, ResolutionKind, Severity,
+};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::{Component, Path, PathBuf};
+
+/// Aggregates the modules under `dir` into a directory view. Imports are grouped by the
+/// directory (or package) on the other side; edges between files inside `dir` are dropped.
+/// `modules` should already be linked by `DependencyGraph::from_modules` so `incoming` is set.
+pub fn aggregate_directory(dir: &Path, modules: &[ModuleIR]) -> DirectoryIR {
+    let root = normalize(dir);
+    let inside: Vec<(&ModuleIR, PathBuf)> = modules
+        .iter()
+        .filter_map(|module| {
+            let path = normalize(Path::new(&module.path));
+            let relative = path.strip_prefix(&root).ok()?.to_path_buf();
+            Some((module, relative))
+        })
+        .collect();
+    let contains = |path: &str| normalize(Path::new(path)).starts_with(&root);
+
+    let mut outgoing = EdgeGroups::default();
+    let mut incoming = EdgeGroups::default();
+    for (module, _) in &inside {
+        for edge in &module.outgoing {
+            let Some(target) = edge.target.as_deref() else {
+                continue;
+            };
+            match edge.resolution {
+                Some(ResolutionKind::External) => {
+                    outgoing.add(target.to_string(), &module.path, edge);
+                }
+                Some(ResolutionKind::Unresolved) => {}
+                _ if !contains(target) => {
+                    outgoing.add(parent_dir(target), &module.path, edge);
+                }
+                _ => {}
+            }
+        }
+        for edge in &module.incoming {
+            match edge.source.as_deref() {
+                Some(source) if !contains(source) => {
+                    incoming.add(parent_dir(source), source, edge);
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let outgoing = outgoing.into_edges(|group, edge| edge.target = Some(group));
+    let incoming = incoming.into_edges(|group, edge| edge.source = Some(group));
+
+    DirectoryIR {
+        path: dir.display().to_string(),
+        children: children(&inside),
+        metrics: DirectoryMetrics {
+            files: inside.len() as u32,
+            loc: inside.iter().map(|(module, _)| module.loc).sum(),
+            fan_out: outgoing.len() as u32,
+            fan_in: incoming.len() as u32,
+        },
+        outgoing,
+        incoming,
+        components: vec![],
+    }
+}
+
+/// Edges merged per directory or package on the far side
+#[derive(Default)]
+struct EdgeGroups<'a> {
+    groups: BTreeMap<String, Vec<(&'a str, &'a DepEdge)>>,
+}
+
+impl<'a> EdgeGroups<'a> {
+    /// `file` is the file that contributes the edge on the aggregated side
+    fn add(&mut self, group: String, file: &'a str, edge: &'a DepEdge) {
+        self.groups.entry(group).or_default().push((file, edge));
+    }
+
+    /// One edge per group: mean strength, `files` = distinct contributing files
+    fn into_edges(self, set_endpoint: impl Fn(String, &mut DepEdge)) -> Vec<DepEdge> {
+        self.groups
+            .into_iter()
+            .map(|(group, edges)| {
+                let files: BTreeSet<&str> = edges.iter().map(|(file, _)| *file).collect();
+                let strength =
+                    edges.iter().map(|(_, edge)| edge.strength).sum::<f32>() / edges.len() as f32;
+                let mut edge = DepEdge {
+                    source: None,
+                    target: None,
+                    relation: DepKind::Import,
+                    strength,
+                    files: Some(files.len() as u32),
+                    span: None,
+                    import_kind: None,
+                    type_only: edges.iter().all(|(_, edge)| edge.type_only),
+                    bindings: vec![],
+                    specifier: None,
+                    resolution: edges[0].1.resolution,
+                };
+                set_endpoint(group, &mut edge);
+                edge
+            })
+            .collect()
+    }
+}
+
+/// Files directly in the directory and its immediate subdirectories, subdirectories first
+fn children(inside: &[(&ModuleIR, PathBuf)]) -> Vec<ChildEntry> {
+    let mut directories: BTreeMap<String, u32> = BTreeMap::new();
+    let mut files = Vec::new();
+
+    for (module, relative) in inside {
+        let mut components = relative.components();
+        let Some(Component::Normal(first)) = components.next() else {
+            continue;
+        };
+        let name = first.to_string_lossy().to_string();
+        if components.next().is_some() {
+            *directories.entry(name).or_default() += module.loc;
+        } else {
+            files.push(ChildEntry {
+                name,
+                entry_type: "file".to_string(),
+                loc: module.loc,
+                metrics: module
+                    .metrics
+                    .iter()
+                    .filter(|m| matches!(m.severity, Severity::Warning | Severity::Error))
+                    .cloned()
+                    .collect(),
+            });
+        }
+    }
+    files.sort_by(|a, b| a.name.cmp(&b.name));
+
+    directories
+        .into_iter()
+        .map(|(name, loc)| ChildEntry {
+            name,
+            entry_type: "directory".to_string(),
+            loc,
+            metrics: vec![],
+        })
+        .chain(files)
+        .collect()
+}
+
+pub(crate) fn parent_dir(path: &str) -> String {
+    let parent = normalize(Path::new(path))
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_default();
+    if parent.as_os_str().is_empty() {
+        ".".to_string()
+    } else {
+        parent.display().to_string()
+    }
+}
+
+#[cfg(test)]
+#[path = "aggregate_test.rs"]
+mod aggregate_test;
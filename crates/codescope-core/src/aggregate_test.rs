@@ -0,0 +1,99 @@
+#[cfg(test)]
+mod tests {
+    use crate::aggregate::aggregate_directory;
+    use crate::graph::DependencyGraph;
+    use crate::test_fixtures::{module, resolved_edge};
+    use crate::types::{ModuleIR, ResolutionKind};
+    use std::path::Path;
+
+    /// `src/core` imports `src/utils` and `react`, and is imported by `src/api`
+    fn create_test_modules() -> Vec<ModuleIR> {
+        use ResolutionKind::{External, Internal};
+        let mut modules = vec![
+            ModuleIR {
+                loc: 100,
+                outgoing: vec![
+                    resolved_edge("src/utils/math.ts", Internal, 0.8),
+                    resolved_edge("src/core/layout.ts", Internal, 0.6),
+                    resolved_edge("react", External, 0.6),
+                ],
+                ..module("src/core/graph.ts")
+            },
+            ModuleIR {
+                loc: 50,
+                outgoing: vec![
+                    resolved_edge("src/utils/math.ts", Internal, 0.6),
+                    resolved_edge("src/utils/color.ts", Internal, 0.6),
+                ],
+                ..module("src/core/layout.ts")
+            },
+            ModuleIR {
+                loc: 30,
+                outgoing: vec![resolved_edge("src/utils/math.ts", Internal, 0.7)],
+                ..module("src/core/parser/tokenizer.ts")
+            },
+            ModuleIR {
+                loc: 20,
+                outgoing: vec![resolved_edge("src/core/graph.ts", Internal, 0.5)],
+                ..module("src/api/graph.ts")
+            },
+            ModuleIR {
+                loc: 10,
+                ..module("src/utils/math.ts")
+            },
+            ModuleIR {
+                loc: 10,
+                ..module("src/utils/color.ts")
+            },
+        ];
+        DependencyGraph::from_modules(&mut modules);
+        modules
+    }
+
+    #[test]
+    fn test_aggregate_outgoing() {
+        let modules = create_test_modules();
+        let dir = aggregate_directory(Path::new("src/core"), &modules);
+
+        let outgoing: Vec<(&str, u32, f32)> = dir
+            .outgoing
+            .iter()
+            .map(|e| (e.target.as_deref().unwrap(), e.files.unwrap(), e.strength))
+            .collect();
+        // Three files import src/utils (four imports, mean strength 0.675)
+        assert_eq!(outgoing, vec![("react", 1, 0.6), ("src/utils", 3, 0.675)]);
+        assert_eq!(dir.outgoing[0].resolution, Some(ResolutionKind::External));
+    }
+
+    #[test]
+    fn test_aggregate_incoming() {
+        let modules = create_test_modules();
+        let dir = aggregate_directory(Path::new("./src/core/"), &modules);
+
+        assert_eq!(dir.incoming.len(), 1);
+        assert_eq!(dir.incoming[0].source.as_deref(), Some("src/api"));
+        assert_eq!(dir.incoming[0].files, Some(1));
+        assert_eq!((dir.metrics.fan_in, dir.metrics.fan_out), (1, 2));
+    }
+
+    #[test]
+    fn test_aggregate_children() {
+        let modules = create_test_modules();
+        let dir = aggregate_directory(Path::new("src/core"), &modules);
+
+        let children: Vec<(&str, &str, u32)> = dir
+            .children
+            .iter()
+            .map(|c| (c.entry_type.as_str(), c.name.as_str(), c.loc))
+            .collect();
+        assert_eq!(
+            children,
+            vec![
+                ("directory", "parser", 30),
+                ("file", "graph.ts", 100),
+                ("file", "layout.ts", 50),
+            ]
+        );
+        assert_eq!((dir.metrics.files, dir.metrics.loc), (3, 180));
+    }
+}
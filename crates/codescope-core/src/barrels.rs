@@ -0,0 +1,74 @@
+use crate::exports::ExportIndex;
+use crate::types::{BindingKind, ImportKind, ModuleIR, RealEdge};
+
+/// Number of `export ... from` statements in `module`
+pub fn reexport_count(module: &ModuleIR) -> usize {
+    module
+        .outgoing
+        .iter()
+        .filter(|edge| edge.import_kind == Some(ImportKind::ReExport))
+        .count()
+}
+
+/// A barrel mostly forwards other modules' exports: it has more `export ... from`
+/// statements than exports of its own, like an `index.ts` made of `export * from` lines
+pub fn is_barrel(module: &ModuleIR) -> bool {
+    reexport_count(module) > module.exports.len()
+}
+
+/// Real edges behind the static imports whose names are re-exported by their target,
+/// one per declaring module. Expects linked modules (see `DependencyGraph::from_modules`).
+pub fn real_edges(modules: &[ModuleIR]) -> Vec<RealEdge> {
+    let index = ExportIndex::new(modules);
+    let mut edges = vec![];
+
+    for module in modules {
+        for edge in &module.outgoing {
+            if edge.import_kind != Some(ImportKind::Static) {
+                continue;
+            }
+            let Some(target) = edge.target.as_deref() else {
+                continue;
+            };
+
+            let mut real: Vec<RealEdge> = vec![];
+            for binding in &edge.bindings {
+                if binding.kind == BindingKind::Namespace {
+                    continue;
+                }
+                let Some(origin) = index.origin(target, &binding.imported) else {
+                    continue;
+                };
+                if origin.via.is_empty() {
+                    continue;
+                }
+                let via: Vec<String> = origin.via.iter().map(|path| path.to_string()).collect();
+                let type_only = edge.type_only || binding.type_only;
+                match real
+                    .iter_mut()
+                    .find(|r| r.target == origin.path && r.via == via)
+                {
+                    Some(existing) => {
+                        existing.names.push(binding.imported.clone());
+                        existing.type_only &= type_only;
+                    }
+                    None => real.push(RealEdge {
+                        source: module.path.clone(),
+                        target: origin.path.to_string(),
+                        via,
+                        names: vec![binding.imported.clone()],
+                        span: edge.span,
+                        type_only,
+                    }),
+                }
+            }
+            edges.extend(real);
+        }
+    }
+
+    edges
+}
+
+#[cfg(test)]
+#[path = "barrels_test.rs"]
+mod barrels_test;
@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod tests {
+    use crate::barrels::{is_barrel, real_edges};
+    use crate::exports::ExportIndex;
+    use crate::graph::DependencyGraph;
+    use crate::rules::barrels::BarrelRule;
+    use crate::rules::QualityRule;
+    use crate::test_fixtures::{import_edge, module_exporting};
+    use crate::types::{ImportKind, ModuleIR, Severity};
+
+    /// `app.ts` imports from the `ui` barrel, which forwards a nested `forms` barrel
+    fn create_project() -> Vec<ModuleIR> {
+        use ImportKind::{ReExport, Static};
+        vec![
+            module_exporting(
+                "app.ts",
+                &[],
+                vec![
+                    import_edge(
+                        "ui/index.ts",
+                        Static,
+                        &[("Button", "Button"), ("Input", "Input"), ("Form", "Form")],
+                    ),
+                    import_edge("ui/index.ts", Static, &[("*", "ui")]),
+                    import_edge("ui/theme.ts", Static, &[("colors", "colors")]),
+                ],
+            ),
+            module_exporting(
+                "ui/index.ts",
+                &[],
+                vec![
+                    import_edge("ui/button.ts", ReExport, &[("*", "*")]),
+                    import_edge("ui/forms/index.ts", ReExport, &[("*", "*")]),
+                    import_edge("ui/theme.ts", ReExport, &[("default", "theme")]),
+                ],
+            ),
+            module_exporting(
+                "ui/forms/index.ts",
+                &["Form"],
+                vec![import_edge(
+                    "ui/forms/input.ts",
+                    ReExport,
+                    &[("Input", "Input")],
+                )],
+            ),
+            module_exporting("ui/button.ts", &["Button"], vec![]),
+            module_exporting("ui/forms/input.ts", &["Input"], vec![]),
+            module_exporting("ui/theme.ts", &["colors", "default"], vec![]),
+        ]
+    }
+
+    #[test]
+    fn test_is_barrel() {
+        let modules = create_project();
+        let barrels: Vec<&str> = modules
+            .iter()
+            .filter(|m| is_barrel(m))
+            .map(|m| m.path.as_str())
+            .collect();
+
+        // `ui/forms/index.ts` exports as much as it forwards
+        assert_eq!(barrels, vec!["ui/index.ts"]);
+    }
+
+    #[test]
+    fn test_origin() {
+        let modules = create_project();
+        let index = ExportIndex::new(&modules);
+
+        let origin = index.origin("ui/index.ts", "Input").unwrap();
+        assert_eq!(origin.path, "ui/forms/input.ts");
+        assert_eq!(origin.name, "Input");
+        assert_eq!(origin.via, vec!["ui/index.ts", "ui/forms/index.ts"]);
+
+        let origin = index.origin("ui/index.ts", "theme").unwrap();
+        assert_eq!(
+            (origin.path, origin.name.as_str()),
+            ("ui/theme.ts", "default")
+        );
+
+        let origin = index.origin("ui/button.ts", "Button").unwrap();
+        assert!(origin.via.is_empty());
+
+        assert!(index.origin("ui/index.ts", "missing").is_none());
+    }
+
+    #[test]
+    fn test_real_edges() {
+        let mut modules = create_project();
+        let graph = DependencyGraph::from_modules(&mut modules);
+
+        let found: Vec<(&str, &str, Vec<&str>, Vec<&str>)> = graph
+            .real_edges()
+            .iter()
+            .map(|edge| {
+                (
+                    edge.source.as_str(),
+                    edge.target.as_str(),
+                    edge.via.iter().map(|v| v.as_str()).collect(),
+                    edge.names.iter().map(|n| n.as_str()).collect(),
+                )
+            })
+            .collect();
+
+        // Namespace imports and direct imports keep only their apparent edge
+        assert_eq!(
+            found,
+            vec![
+                (
+                    "app.ts",
+                    "ui/button.ts",
+                    vec!["ui/index.ts"],
+                    vec!["Button"]
+                ),
+                (
+                    "app.ts",
+                    "ui/forms/input.ts",
+                    vec!["ui/index.ts", "ui/forms/index.ts"],
+                    vec!["Input"]
+                ),
+                (
+                    "app.ts",
+                    "ui/forms/index.ts",
+                    vec!["ui/index.ts"],
+                    vec!["Form"]
+                ),
+            ]
+        );
+        assert_eq!(graph.get_outgoing("app.ts").len(), 3);
+        assert_eq!(real_edges(&modules), graph.real_edges());
+    }
+
+    #[test]
+    fn test_barrel_rule() {
+        let modules = create_project();
+        let rule = BarrelRule::new(2, Severity::Warning);
+
+        let metrics = rule.check_module(&modules[1]);
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].value, 3.0);
+        assert_eq!(metrics[0].severity, Severity::Warning);
+        assert_eq!(
+            metrics[0].message.as_deref(),
+            Some("Barrel has 3 re-exports, exceeds threshold of 2")
+        );
+
+        assert_eq!(
+            BarrelRule::default().check_module(&modules[1])[0].severity,
+            Severity::Info
+        );
+        assert!(rule.check_module(&modules[2]).is_empty());
+    }
+}
@@ -0,0 +1,185 @@
+use crate::graph::DependencyGraph;
+use crate::types::{ModuleIR, QualityMetric, Severity};
+use std::collections::{HashMap, VecDeque};
+
+const DAMPING: f64 = 0.85;
+const MAX_ITERATIONS: usize = 100;
+const TOLERANCE: f64 = 1e-9;
+
+/// Modules as indices with their distinct imports
+struct Adjacency<'a> {
+    paths: Vec<&'a str>,
+    imports: Vec<Vec<usize>>,
+}
+
+impl<'a> Adjacency<'a> {
+    fn new(graph: &'a DependencyGraph) -> Self {
+        let paths: Vec<&str> = graph.modules().collect();
+        let index: HashMap<&str, usize> = paths.iter().enumerate().map(|(i, p)| (*p, i)).collect();
+        let mut imports: Vec<Vec<usize>> = vec![vec![]; paths.len()];
+        for (source, target, _) in graph.edges() {
+            imports[index[source]].push(index[target]);
+        }
+        for targets in &mut imports {
+            targets.sort_unstable();
+            targets.dedup();
+        }
+        Self { paths, imports }
+    }
+
+    fn into_map(self, values: Vec<f64>) -> HashMap<String, f64> {
+        self.paths
+            .into_iter()
+            .map(String::from)
+            .zip(values)
+            .collect()
+    }
+}
+
+/// PageRank along import edges, so rank flows to the modules everything depends on.
+/// Scores are scaled so the average module has 1.0.
+pub fn pagerank(graph: &DependencyGraph) -> HashMap<String, f64> {
+    let adjacency = Adjacency::new(graph);
+    let n = adjacency.paths.len();
+    if n == 0 {
+        return HashMap::new();
+    }
+
+    let mut rank = vec![1.0 / n as f64; n];
+    for _ in 0..MAX_ITERATIONS {
+        // Modules importing nothing spread their rank over every module
+        let dangling: f64 = (0..n)
+            .filter(|&i| adjacency.imports[i].is_empty())
+            .map(|i| rank[i])
+            .sum();
+        let base = (1.0 - DAMPING + DAMPING * dangling) / n as f64;
+        let mut next = vec![base; n];
+        for (source, targets) in adjacency.imports.iter().enumerate() {
+            let share = DAMPING * rank[source] / targets.len().max(1) as f64;
+            for &target in targets {
+                next[target] += share;
+            }
+        }
+
+        let delta: f64 = next.iter().zip(&rank).map(|(a, b)| (a - b).abs()).sum();
+        rank = next;
+        if delta < TOLERANCE {
+            break;
+        }
+    }
+
+    let scaled = rank.into_iter().map(|r| r * n as f64).collect();
+    adjacency.into_map(scaled)
+}
+
+/// Betweenness centrality (Brandes): how many shortest import chains between other
+/// modules pass through each module
+pub fn betweenness(graph: &DependencyGraph) -> HashMap<String, f64> {
+    let adjacency = Adjacency::new(graph);
+    let n = adjacency.paths.len();
+    let mut centrality = vec![0.0; n];
+
+    for source in 0..n {
+        let mut order = Vec::with_capacity(n);
+        let mut predecessors: Vec<Vec<usize>> = vec![vec![]; n];
+        let mut paths = vec![0.0; n];
+        let mut distance: Vec<Option<usize>> = vec![None; n];
+        paths[source] = 1.0;
+        distance[source] = Some(0);
+
+        let mut queue = VecDeque::from([source]);
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            let next_distance = distance[node].unwrap_or(0) + 1;
+            for &target in &adjacency.imports[node] {
+                if distance[target].is_none() {
+                    distance[target] = Some(next_distance);
+                    queue.push_back(target);
+                }
+                if distance[target] == Some(next_distance) {
+                    paths[target] += paths[node];
+                    predecessors[target].push(node);
+                }
+            }
+        }
+
+        let mut dependency = vec![0.0; n];
+        for &node in order.iter().rev() {
+            for &previous in &predecessors[node] {
+                dependency[previous] += paths[previous] / paths[node] * (1.0 + dependency[node]);
+            }
+            if node != source {
+                centrality[node] += dependency[node];
+            }
+        }
+    }
+
+    adjacency.into_map(centrality)
+}
+
+/// Adds a `risk_score` metric to modules carrying `pagerank` and `betweenness` metrics:
+/// centrality relative to the most central module (0 to 1) times the highest symbol complexity
+pub fn assign_risk_scores(modules: &mut [ModuleIR]) {
+    let metric = |module: &ModuleIR, name: &str| {
+        module
+            .metrics
+            .iter()
+            .find(|m| m.name == name)
+            .map(|m| m.value)
+    };
+    let max_of = |name: &str, modules: &[ModuleIR]| {
+        modules
+            .iter()
+            .filter_map(|m| metric(m, name))
+            .fold(0.0, f64::max)
+    };
+    let max_pagerank = max_of("pagerank", modules);
+    let max_betweenness = max_of("betweenness", modules);
+
+    for module in modules.iter_mut() {
+        let (Some(pagerank), Some(betweenness)) =
+            (metric(module, "pagerank"), metric(module, "betweenness"))
+        else {
+            continue;
+        };
+        let relative = |value: f64, max: f64| if max > 0.0 { value / max } else { 0.0 };
+        let centrality =
+            (relative(pagerank, max_pagerank) + relative(betweenness, max_betweenness)) / 2.0;
+        let complexity = module
+            .symbols
+            .iter()
+            .filter_map(|s| s.cyclomatic_complexity)
+            .max()
+            .unwrap_or(1);
+
+        module.metrics.push(QualityMetric {
+            name: "risk_score".to_string(),
+            value: centrality * complexity as f64,
+            threshold: None,
+            severity: Severity::Info,
+            message: None,
+        });
+    }
+}
+
+/// Paths of the `limit` modules with the highest non-zero `risk_score`, riskiest first
+pub fn risk_hotspots(modules: &[ModuleIR], limit: usize) -> Vec<String> {
+    let mut scored: Vec<(&str, f64)> = modules
+        .iter()
+        .filter_map(|module| {
+            let risk = module.metrics.iter().find(|m| m.name == "risk_score")?;
+            (risk.value > 0.0).then_some((module.path.as_str(), risk.value))
+        })
+        .collect();
+    scored.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+
+    scored
+        .into_iter()
+        .take(limit)
+        .map(|(path, _)| path.to_string())
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "centrality_test.rs"]
+mod centrality_test;
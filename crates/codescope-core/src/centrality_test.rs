@@ -0,0 +1,82 @@
+#[cfg(test)]
+mod tests {
+    use crate::centrality::{assign_risk_scores, betweenness, pagerank, risk_hotspots};
+    use crate::graph::DependencyGraph;
+    use crate::test_fixtures::{metric, module_importing, symbol};
+    use crate::types::{ModuleIR, Severity, Symbol, SymbolKind};
+
+    /// Two entry points funnel through `hub.ts` into `db.ts`
+    fn create_test_graph() -> (Vec<ModuleIR>, DependencyGraph) {
+        let mut modules = vec![
+            module_importing("cli.ts", &["hub.ts"]),
+            module_importing("web.ts", &["hub.ts", "hub.ts"]),
+            module_importing("hub.ts", &["db.ts"]),
+            module_importing("db.ts", &[]),
+        ];
+        let graph = DependencyGraph::from_modules(&mut modules);
+        (modules, graph)
+    }
+
+    #[test]
+    fn test_betweenness() {
+        let (_, graph) = create_test_graph();
+        let scores = betweenness(&graph);
+
+        // cli -> db and web -> db both pass through hub; a repeated import counts once
+        assert_eq!(scores["hub.ts"], 2.0);
+        assert_eq!(scores["cli.ts"], 0.0);
+        assert_eq!(scores["db.ts"], 0.0);
+    }
+
+    #[test]
+    fn test_pagerank() {
+        let (_, graph) = create_test_graph();
+        let ranks = pagerank(&graph);
+
+        let total: f64 = ranks.values().sum();
+        assert!((total - 4.0).abs() < 1e-6);
+        assert!(ranks["db.ts"] > ranks["hub.ts"]);
+        assert!(ranks["hub.ts"] > ranks["cli.ts"]);
+        assert!((ranks["cli.ts"] - ranks["web.ts"]).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_risk_hotspots() {
+        let mut modules = vec![
+            module_importing("hub.ts", &[]),
+            module_importing("db.ts", &[]),
+            module_importing("cli.ts", &[]),
+            module_importing("notes.ts", &[]),
+        ];
+        for (module, (pagerank, betweenness)) in
+            modules.iter_mut().zip([(2.0, 2.0), (4.0, 0.0), (0.5, 0.0)])
+        {
+            module
+                .metrics
+                .push(metric("pagerank", pagerank, Severity::Info));
+            module
+                .metrics
+                .push(metric("betweenness", betweenness, Severity::Info));
+        }
+        modules[0].symbols.push(Symbol {
+            loc: 20,
+            cyclomatic_complexity: Some(6),
+            ..symbol("route", SymbolKind::Function)
+        });
+
+        assign_risk_scores(&mut modules);
+
+        // hub: (2/4 + 2/2) / 2 * 6 = 4.5; db: (4/4 + 0) / 2 * 1 = 0.5
+        let risk = |module: &ModuleIR| {
+            module
+                .metrics
+                .iter()
+                .find(|m| m.name == "risk_score")
+                .map(|m| m.value)
+        };
+        assert_eq!(risk(&modules[0]), Some(4.5));
+        assert_eq!(risk(&modules[1]), Some(0.5));
+        assert_eq!(risk(&modules[3]), None);
+        assert_eq!(risk_hotspots(&modules, 2), vec!["hub.ts", "db.ts"]);
+    }
+}
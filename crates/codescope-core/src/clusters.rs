@@ -0,0 +1,246 @@
+use crate::aggregate::parent_dir;
+use crate::graph::DependencyGraph;
+use crate::types::{Cluster, ClusterReport, MisplacedModule};
+use std::collections::{BTreeMap, HashMap};
+
+/// Local moving passes per Louvain level
+const MAX_PASSES: usize = 100;
+
+/// Undirected view of the import graph: one unit of weight per importing direction.
+/// `links[i][i]` holds twice the weight inside node `i`, so row sums are node degrees.
+struct WeightedGraph {
+    links: Vec<BTreeMap<usize, f64>>,
+}
+
+impl WeightedGraph {
+    fn degree(&self, node: usize) -> f64 {
+        self.links[node].values().sum()
+    }
+
+    fn total(&self) -> f64 {
+        (0..self.links.len()).map(|i| self.degree(i)).sum()
+    }
+
+    /// Merges nodes into their communities
+    fn aggregate(&self, community: &[usize], count: usize) -> Self {
+        let mut links = vec![BTreeMap::new(); count];
+        for (node, neighbors) in self.links.iter().enumerate() {
+            for (&neighbor, &weight) in neighbors {
+                *links[community[node]]
+                    .entry(community[neighbor])
+                    .or_insert(0.0) += weight;
+            }
+        }
+        Self { links }
+    }
+
+    /// Newman modularity of a partition given as one community per node
+    fn modularity(&self, community: &[usize]) -> f64 {
+        let total = self.total();
+        if total == 0.0 {
+            return 0.0;
+        }
+
+        let mut inside: HashMap<usize, f64> = HashMap::new();
+        let mut degrees: HashMap<usize, f64> = HashMap::new();
+        for (node, neighbors) in self.links.iter().enumerate() {
+            let c = community[node];
+            *degrees.entry(c).or_insert(0.0) += self.degree(node);
+            for (&neighbor, &weight) in neighbors {
+                if community[neighbor] == c {
+                    *inside.entry(c).or_insert(0.0) += weight;
+                }
+            }
+        }
+        degrees
+            .iter()
+            .map(|(c, degree)| inside.get(c).unwrap_or(&0.0) / total - (degree / total).powi(2))
+            .sum()
+    }
+}
+
+/// Louvain local moving: each node joins the neighboring community with the best
+/// modularity gain until no move helps. Returns dense community ids and their count.
+fn local_moving(graph: &WeightedGraph) -> (Vec<usize>, usize) {
+    let n = graph.links.len();
+    let total = graph.total();
+    let degree: Vec<f64> = (0..n).map(|i| graph.degree(i)).collect();
+    let mut community: Vec<usize> = (0..n).collect();
+    let mut community_degree = degree.clone();
+
+    for _ in 0..MAX_PASSES {
+        let mut moved = false;
+        for node in 0..n {
+            let current = community[node];
+            let mut weights: BTreeMap<usize, f64> = BTreeMap::new();
+            for (&neighbor, &weight) in &graph.links[node] {
+                if neighbor != node {
+                    *weights.entry(community[neighbor]).or_insert(0.0) += weight;
+                }
+            }
+
+            community_degree[current] -= degree[node];
+            let gain = |c: usize, weight: f64| weight - community_degree[c] * degree[node] / total;
+            let mut best = current;
+            let mut best_gain = gain(current, weights.get(&current).copied().unwrap_or(0.0));
+            for (&c, &weight) in &weights {
+                let candidate = gain(c, weight);
+                if candidate > best_gain + 1e-12 {
+                    best = c;
+                    best_gain = candidate;
+                }
+            }
+            community_degree[best] += degree[node];
+
+            if best != current {
+                community[node] = best;
+                moved = true;
+            }
+        }
+        if !moved {
+            break;
+        }
+    }
+
+    // Renumber in order of first appearance
+    let mut ids: HashMap<usize, usize> = HashMap::new();
+    for c in &mut community {
+        let next = ids.len();
+        *c = *ids.entry(*c).or_insert(next);
+    }
+    (community, ids.len())
+}
+
+/// Modules that import or are imported by another module, with their weighted links
+fn linked_modules(graph: &DependencyGraph) -> (Vec<String>, WeightedGraph) {
+    let mut pairs: BTreeMap<(&str, &str), f64> = BTreeMap::new();
+    for (source, target, _) in graph.edges() {
+        if source != target {
+            pairs.insert((source, target), 1.0);
+        }
+    }
+
+    let mut paths: Vec<String> = pairs
+        .keys()
+        .flat_map(|(source, target)| [source.to_string(), target.to_string()])
+        .collect();
+    paths.sort();
+    paths.dedup();
+    let index: HashMap<&str, usize> = paths
+        .iter()
+        .enumerate()
+        .map(|(i, p)| (p.as_str(), i))
+        .collect();
+
+    let mut links = vec![BTreeMap::new(); paths.len()];
+    for ((source, target), weight) in pairs {
+        let (a, b) = (index[source], index[target]);
+        *links[a].entry(b).or_insert(0.0) += weight;
+        *links[b].entry(a).or_insert(0.0) += weight;
+    }
+    (paths, WeightedGraph { links })
+}
+
+/// Community of every module under the Louvain method, one entry per module in `paths` order
+fn louvain(graph: &WeightedGraph) -> Vec<usize> {
+    let mut membership: Vec<usize> = (0..graph.links.len()).collect();
+    let mut level = WeightedGraph {
+        links: graph.links.clone(),
+    };
+    loop {
+        let (community, count) = local_moving(&level);
+        if count == level.links.len() {
+            return membership;
+        }
+        for c in &mut membership {
+            *c = community[*c];
+        }
+        level = level.aggregate(&community, count);
+    }
+}
+
+/// Groups linked modules into import communities (Louvain modularity optimization) and
+/// compares them to the directory layout. Modules without imports either way are left out.
+pub fn detect_clusters(graph: &DependencyGraph) -> ClusterReport {
+    let (paths, weighted) = linked_modules(graph);
+    let membership = louvain(&weighted);
+
+    let directories: Vec<String> = paths.iter().map(|p| parent_dir(p)).collect();
+    let mut directory_ids: HashMap<&str, usize> = HashMap::new();
+    let directory_membership: Vec<usize> = directories
+        .iter()
+        .map(|dir| {
+            let next = directory_ids.len();
+            *directory_ids.entry(dir.as_str()).or_insert(next)
+        })
+        .collect();
+
+    let mut members: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+    for (node, &c) in membership.iter().enumerate() {
+        members.entry(c).or_default().push(node);
+    }
+
+    let mut clusters = Vec::new();
+    let mut misplaced = Vec::new();
+    for nodes in members.into_values() {
+        let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+        for &node in &nodes {
+            *counts.entry(directories[node].as_str()).or_insert(0) += 1;
+        }
+        let mut spanned: Vec<(&str, usize)> = counts.into_iter().collect();
+        spanned.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(b.0)));
+        let home = spanned[0].0;
+
+        for &node in &nodes {
+            if directories[node] == home {
+                continue;
+            }
+            let links_to = |dir: &str| {
+                weighted.links[node]
+                    .iter()
+                    .filter(|(&neighbor, _)| neighbor != node && directories[neighbor] == dir)
+                    .map(|(_, weight)| *weight)
+                    .sum::<f64>() as u32
+            };
+            let (own_links, suggested_links) = (links_to(&directories[node]), links_to(home));
+            if suggested_links > own_links {
+                misplaced.push(MisplacedModule {
+                    path: paths[node].clone(),
+                    directory: directories[node].clone(),
+                    suggested: home.to_string(),
+                    own_links,
+                    suggested_links,
+                });
+            }
+        }
+
+        clusters.push(Cluster {
+            directory: home.to_string(),
+            directories: spanned.iter().map(|(dir, _)| dir.to_string()).collect(),
+            modules: nodes.iter().map(|&node| paths[node].clone()).collect(),
+        });
+    }
+
+    clusters.sort_by(|a, b| {
+        b.modules
+            .len()
+            .cmp(&a.modules.len())
+            .then_with(|| a.modules.cmp(&b.modules))
+    });
+    misplaced.sort_by(|a, b| {
+        (b.suggested_links - b.own_links)
+            .cmp(&(a.suggested_links - a.own_links))
+            .then_with(|| a.path.cmp(&b.path))
+    });
+
+    ClusterReport {
+        modularity: weighted.modularity(&membership),
+        directory_modularity: weighted.modularity(&directory_membership),
+        clusters,
+        misplaced,
+    }
+}
+
+#[cfg(test)]
+#[path = "clusters_test.rs"]
+mod clusters_test;
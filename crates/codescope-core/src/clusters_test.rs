@@ -0,0 +1,68 @@
+#[cfg(test)]
+mod tests {
+    use crate::clusters::detect_clusters;
+    use crate::graph::DependencyGraph;
+    use crate::test_fixtures::module_importing;
+
+    /// Two tightly knit directories joined by one import, plus `src/a/stray.ts`
+    /// which only talks to `src/b` and `src/a/lonely.ts` which talks to no one
+    fn create_test_graph() -> DependencyGraph {
+        let mut modules = vec![
+            module_importing("src/a/a1.ts", &["src/a/a2.ts", "src/a/a3.ts"]),
+            module_importing("src/a/a2.ts", &["src/a/a3.ts"]),
+            module_importing("src/a/a3.ts", &["src/b/b1.ts"]),
+            module_importing("src/a/stray.ts", &["src/b/b1.ts", "src/b/b2.ts"]),
+            module_importing("src/a/lonely.ts", &[]),
+            module_importing("src/b/b1.ts", &["src/b/b2.ts", "src/b/b3.ts"]),
+            module_importing("src/b/b2.ts", &["src/b/b3.ts"]),
+            module_importing("src/b/b3.ts", &["src/a/stray.ts"]),
+        ];
+        DependencyGraph::from_modules(&mut modules)
+    }
+
+    #[test]
+    fn test_detect_clusters() {
+        let report = detect_clusters(&create_test_graph());
+
+        assert_eq!(report.clusters.len(), 2);
+        let b = &report.clusters[0];
+        assert_eq!(b.directory, "src/b");
+        assert_eq!(b.directories, vec!["src/b", "src/a"]);
+        assert_eq!(
+            b.modules,
+            vec![
+                "src/a/stray.ts",
+                "src/b/b1.ts",
+                "src/b/b2.ts",
+                "src/b/b3.ts"
+            ]
+        );
+        assert_eq!(
+            report.clusters[1].modules,
+            vec!["src/a/a1.ts", "src/a/a2.ts", "src/a/a3.ts"]
+        );
+        assert!(report.modularity > report.directory_modularity);
+    }
+
+    #[test]
+    fn test_misplaced_modules() {
+        let report = detect_clusters(&create_test_graph());
+
+        assert_eq!(report.misplaced.len(), 1);
+        let stray = &report.misplaced[0];
+        assert_eq!(stray.path, "src/a/stray.ts");
+        assert_eq!(
+            (stray.directory.as_str(), stray.suggested.as_str()),
+            ("src/a", "src/b")
+        );
+        assert_eq!((stray.own_links, stray.suggested_links), (0, 3));
+    }
+
+    #[test]
+    fn test_empty_graph() {
+        let report = detect_clusters(&DependencyGraph::new());
+
+        assert!(report.clusters.is_empty());
+        assert_eq!(report.modularity, 0.0);
+    }
+}
@@ -0,0 +1,131 @@
+use crate::graph::DependencyGraph;
+use crate::resolve::normalize;
+use crate::rules::layers::Layer;
+use crate::types::{ComponentMetrics, ModuleIR, SymbolKind};
+use std::collections::{BTreeMap, BTreeSet};
+use std::path::Path;
+
+/// How modules are grouped into components for package metrics
+pub enum Components {
+    /// Each directory with the files directly inside it
+    Directories,
+    /// Architecture layers; files outside every layer belong to no component
+    Layers(Vec<Layer>),
+}
+
+impl Components {
+    pub fn component_of(&self, path: &str) -> Option<String> {
+        match self {
+            Components::Directories => {
+                let parent = normalize(Path::new(path)).parent()?.to_path_buf();
+                Some(if parent.as_os_str().is_empty() {
+                    ".".to_string()
+                } else {
+                    parent.display().to_string()
+                })
+            }
+            Components::Layers(layers) => layers
+                .iter()
+                .find(|layer| layer.contains(path))
+                .map(|layer| layer.name.clone()),
+        }
+    }
+}
+
+/// Ca and Ce per component, from the module edges of `graph`
+pub fn coupling(graph: &DependencyGraph, components: &Components) -> BTreeMap<String, (u32, u32)> {
+    let mut afferent: BTreeMap<String, BTreeSet<&str>> = BTreeMap::new();
+    let mut efferent: BTreeMap<String, BTreeSet<&str>> = BTreeMap::new();
+
+    for (source, target, _) in graph.edges() {
+        let (Some(from), Some(to)) = (
+            components.component_of(source),
+            components.component_of(target),
+        ) else {
+            continue;
+        };
+        if from != to {
+            efferent.entry(from).or_default().insert(target);
+            afferent.entry(to).or_default().insert(source);
+        }
+    }
+
+    let names: BTreeSet<&String> = afferent.keys().chain(efferent.keys()).collect();
+    names
+        .into_iter()
+        .map(|name| {
+            let count = |sets: &BTreeMap<String, BTreeSet<&str>>| {
+                sets.get(name).map_or(0, |set| set.len() as u32)
+            };
+            (name.clone(), (count(&afferent), count(&efferent)))
+        })
+        .collect()
+}
+
+/// I = Ce / (Ca + Ce); a component nothing depends on either way counts as stable
+pub fn instability(afferent: u32, efferent: u32) -> f64 {
+    if afferent + efferent == 0 {
+        0.0
+    } else {
+        efferent as f64 / (afferent + efferent) as f64
+    }
+}
+
+/// Package metrics for every component holding at least one of `modules`, sorted by name
+pub fn component_metrics(
+    graph: &DependencyGraph,
+    modules: &[ModuleIR],
+    components: &Components,
+) -> Vec<ComponentMetrics> {
+    let coupling = coupling(graph, components);
+
+    // (files, abstract types, all types)
+    let mut counts: BTreeMap<String, (u32, u32, u32)> = BTreeMap::new();
+    for module in modules {
+        let Some(name) = components.component_of(&module.path) else {
+            continue;
+        };
+        let entry = counts.entry(name).or_default();
+        entry.0 += 1;
+        for symbol in &module.symbols {
+            match symbol.kind {
+                SymbolKind::Interface | SymbolKind::Type => {
+                    entry.1 += 1;
+                    entry.2 += 1;
+                }
+                SymbolKind::Class if symbol.is_abstract => {
+                    entry.1 += 1;
+                    entry.2 += 1;
+                }
+                SymbolKind::Class | SymbolKind::Enum => entry.2 += 1,
+                _ => {}
+            }
+        }
+    }
+
+    counts
+        .into_iter()
+        .map(|(name, (files, abstract_types, types))| {
+            let (afferent, efferent) = coupling.get(&name).copied().unwrap_or_default();
+            let instability = instability(afferent, efferent);
+            let abstractness = if types == 0 {
+                0.0
+            } else {
+                abstract_types as f64 / types as f64
+            };
+            ComponentMetrics {
+                name,
+                files,
+                afferent,
+                efferent,
+                instability,
+                abstractness,
+                distance: (abstractness + instability - 1.0).abs(),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+#[path = "components_test.rs"]
+mod components_test;
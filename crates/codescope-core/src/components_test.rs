@@ -0,0 +1,108 @@
+#[cfg(test)]
+mod tests {
+    use crate::components::{component_metrics, Components};
+    use crate::graph::DependencyGraph;
+    use crate::rules::layers::Layer;
+    use crate::rules::stable_dependencies::StableDependenciesRule;
+    use crate::rules::QualityRule;
+    use crate::test_fixtures::{module_importing, symbol};
+    use crate::types::{ModuleIR, Severity, Symbol, SymbolKind};
+    use std::path::Path;
+
+    /// `src/domain` is mostly abstract; `src/app` and `src/infra` import it
+    fn create_test_modules() -> Vec<ModuleIR> {
+        use SymbolKind::{Class, Interface, Type};
+        vec![
+            module_importing(
+                "src/app/main.ts",
+                &["src/domain/user.ts", "src/infra/db.ts"],
+            ),
+            ModuleIR {
+                symbols: vec![
+                    symbol("User", Interface),
+                    Symbol {
+                        is_abstract: true,
+                        ..symbol("Entity", Class)
+                    },
+                ],
+                ..module_importing("src/domain/user.ts", &[])
+            },
+            ModuleIR {
+                symbols: vec![symbol("Order", Class), symbol("OrderId", Type)],
+                ..module_importing("src/domain/order.ts", &["src/infra/db.ts"])
+            },
+            ModuleIR {
+                symbols: vec![symbol("Database", Class)],
+                ..module_importing("src/infra/db.ts", &["src/domain/user.ts"])
+            },
+            module_importing("src/infra/log.ts", &["src/domain/order.ts"]),
+        ]
+    }
+
+    #[test]
+    fn test_component_metrics() {
+        let mut modules = create_test_modules();
+        let graph = DependencyGraph::from_modules(&mut modules);
+        let metrics = component_metrics(&graph, &modules, &Components::Directories);
+
+        let names: Vec<&str> = metrics.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(names, vec!["src/app", "src/domain", "src/infra"]);
+
+        // domain: imported by main.ts, db.ts and log.ts, imports db.ts; 3 of 4 types abstract
+        let domain = &metrics[1];
+        assert_eq!((domain.files, domain.afferent, domain.efferent), (2, 3, 1));
+        assert_eq!(
+            (domain.instability, domain.abstractness, domain.distance),
+            (0.25, 0.75, 0.0)
+        );
+
+        let app = &metrics[0];
+        assert_eq!(
+            (app.instability, app.abstractness, app.distance),
+            (1.0, 0.0, 0.0)
+        );
+    }
+
+    #[test]
+    fn test_layer_components() {
+        let mut modules = create_test_modules();
+        let graph = DependencyGraph::from_modules(&mut modules);
+        let components = Components::Layers(vec![Layer::new(
+            "core",
+            &["src/domain/**".to_string(), "src/infra/**".to_string()],
+        )
+        .unwrap()]);
+        let metrics = component_metrics(&graph, &modules, &components);
+
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(
+            (metrics[0].files, metrics[0].afferent, metrics[0].efferent),
+            (4, 0, 0)
+        );
+
+        let rooted = Components::Layers(vec![Layer::new("ui", &["src/ui/**".to_string()])
+            .unwrap()
+            .with_root(Path::new("/home/dev/project"))]);
+        assert_eq!(
+            rooted.component_of("/home/dev/project/src/ui/button.ts"),
+            Some("ui".to_string())
+        );
+        assert_eq!(
+            rooted.component_of("/home/dev/other/src/ui/button.ts"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_stable_dependencies_rule() {
+        let mut modules = create_test_modules();
+        let graph = DependencyGraph::from_modules(&mut modules);
+        let rule = StableDependenciesRule::new(Components::Directories, Severity::Warning);
+
+        // domain (I=0.25) -> infra (I=0.5) goes towards a less stable component
+        let metrics = rule.check_graph(&graph);
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].path, "src/domain/order.ts");
+        assert_eq!(metrics[0].metric.name, "unstable_dependency");
+    }
+}
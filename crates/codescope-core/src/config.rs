@@ -1,14 +1,60 @@
+use crate::components::Components;
+use crate::reachability::EntryPoints;
 use crate::rules::*;
 use crate::types::Severity;
-use anyhow::Result;
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Config {
     #[serde(default)]
     pub rules: RulesConfig,
+
+    #[serde(default)]
+    pub architecture: ArchitectureConfig,
+
+    #[serde(default)]
+    pub reachability: ReachabilityConfig,
+
+    /// Project directory the config applies to, set by the caller: entry point globs and
+    /// `package.json` are looked up there (the working directory when unset)
+    #[serde(skip)]
+    pub root: Option<PathBuf>,
+}
+
+/// Layers ordered top to bottom: a layer may import itself and the layers after it
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ArchitectureConfig {
+    #[serde(default)]
+    pub layers: Vec<LayerConfig>,
+
+    /// Dependencies rejected even when they follow the layer order
+    #[serde(default)]
+    pub forbidden: Vec<ForbiddenDependency>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LayerConfig {
+    pub name: String,
+    /// Globs matched against file paths, e.g. `src/ui/**`
+    pub paths: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ForbiddenDependency {
+    pub from: String,
+    pub to: String,
+}
+
+/// Where programs start, for finding files nothing imports
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ReachabilityConfig {
+    /// Globs matched against paths relative to the project root, e.g. `scripts/*.ts`.
+    /// `package.json` `main`, `bin` and `exports` entries are always included.
+    #[serde(default)]
+    pub entry_points: Vec<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +80,29 @@ pub struct RulesConfig {
     #[serde(default = "default_max_complexity")]
     pub max_complexity: u32,
 
+    #[serde(default = "default_max_exports")]
+    pub max_exports: usize,
+
+    /// Maximum `export ... from` statements in a barrel module
+    #[serde(default = "default_max_barrel_reexports")]
+    pub max_barrel_reexports: usize,
+
+    /// Flag imports from a component into a less stable one (off by default)
+    #[serde(default)]
+    pub stable_dependencies: bool,
+
+    /// Report PageRank and betweenness per module, used to rank risk hotspots
+    #[serde(default = "default_centrality")]
+    pub centrality: bool,
+
+    /// Flag exports no other module imports (entry point exports are public API)
+    #[serde(default = "default_unused_exports")]
+    pub unused_exports: bool,
+
+    /// Flag relative imports of missing files and imports of names the target does not export
+    #[serde(default = "default_broken_imports")]
+    pub broken_imports: bool,
+
     #[serde(default)]
     pub severity: SeverityConfig,
 }
@@ -51,6 +120,27 @@ pub struct SeverityConfig {
 
     #[serde(default = "default_complexity_severity")]
     pub max_complexity: String,
+
+    #[serde(default = "default_api_surface_severity")]
+    pub max_exports: String,
+
+    #[serde(default = "default_barrel_severity")]
+    pub max_barrel_reexports: String,
+
+    #[serde(default = "default_no_cycles_severity")]
+    pub no_cycles: String,
+
+    #[serde(default = "default_layers_severity")]
+    pub layers: String,
+
+    #[serde(default = "default_stable_dependencies_severity")]
+    pub stable_dependencies: String,
+
+    #[serde(default = "default_unused_exports_severity")]
+    pub unused_exports: String,
+
+    #[serde(default = "default_broken_imports_severity")]
+    pub broken_imports: String,
 }
 
 fn default_max_file_loc() -> u32 {
@@ -74,6 +164,24 @@ fn default_max_imports() -> usize {
 fn default_max_complexity() -> u32 {
     10
 }
+fn default_max_exports() -> usize {
+    20
+}
+fn default_max_barrel_reexports() -> usize {
+    10
+}
+fn default_centrality() -> bool {
+    true
+}
+
+fn default_unused_exports() -> bool {
+    true
+}
+
+fn default_broken_imports() -> bool {
+    true
+}
+
 fn default_file_size_severity() -> String {
     "Warning".to_string()
 }
@@ -86,6 +194,30 @@ fn default_coupling_severity() -> String {
 fn default_complexity_severity() -> String {
     "Warning".to_string()
 }
+fn default_api_surface_severity() -> String {
+    "Warning".to_string()
+}
+fn default_barrel_severity() -> String {
+    "Warning".to_string()
+}
+fn default_no_cycles_severity() -> String {
+    "Warning".to_string()
+}
+fn default_layers_severity() -> String {
+    "Error".to_string()
+}
+
+fn default_stable_dependencies_severity() -> String {
+    "Warning".to_string()
+}
+
+fn default_unused_exports_severity() -> String {
+    "Warning".to_string()
+}
+
+fn default_broken_imports_severity() -> String {
+    "Error".to_string()
+}
 
 impl Default for RulesConfig {
     fn default() -> Self {
@@ -97,6 +229,12 @@ impl Default for RulesConfig {
             max_fan_out: default_max_fan_out(),
             max_imports: default_max_imports(),
             max_complexity: default_max_complexity(),
+            max_exports: default_max_exports(),
+            max_barrel_reexports: default_max_barrel_reexports(),
+            stable_dependencies: false,
+            centrality: default_centrality(),
+            unused_exports: default_unused_exports(),
+            broken_imports: default_broken_imports(),
             severity: SeverityConfig::default(),
         }
     }
@@ -109,30 +247,40 @@ impl Default for SeverityConfig {
             max_function_loc: default_function_size_severity(),
             max_fan_out: default_coupling_severity(),
             max_complexity: default_complexity_severity(),
-        }
-    }
-}
-
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            rules: RulesConfig::default(),
+            max_exports: default_api_surface_severity(),
+            max_barrel_reexports: default_barrel_severity(),
+            no_cycles: default_no_cycles_severity(),
+            layers: default_layers_severity(),
+            stable_dependencies: default_stable_dependencies_severity(),
+            unused_exports: default_unused_exports_severity(),
+            broken_imports: default_broken_imports_severity(),
         }
     }
 }
 
 impl Config {
     pub fn load(path: &Path) -> Result<Self> {
-        let content = fs::read_to_string(path)?;
-        let config: Config = toml::from_str(&content)?;
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let config: Config = toml::from_str(&content)
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        config
+            .architecture
+            .layers(Path::new("."))
+            .with_context(|| format!("Invalid [architecture] in {}", path.display()))?;
+        config
+            .reachability
+            .entry_points()
+            .with_context(|| format!("Invalid [reachability] in {}", path.display()))?;
         Ok(config)
     }
 
-    pub fn load_or_default(path: Option<&Path>) -> Self {
-        if let Some(p) = path {
-            Self::load(p).unwrap_or_default()
-        } else {
-            Self::default()
+    /// The config at `path` when given, else the defaults. A config file that cannot be
+    /// loaded is an error rather than silently replaced by the defaults.
+    pub fn load_or_default(path: Option<&Path>) -> Result<Self> {
+        match path {
+            Some(p) => Self::load(p),
+            None => Ok(Self::default()),
         }
     }
 
@@ -170,10 +318,109 @@ impl Config {
             complexity_severity,
         )));
 
+        let api_surface_severity = parse_severity(&self.rules.severity.max_exports);
+        registry.register(Box::new(api_surface::ApiSurfaceRule::new(
+            self.rules.max_exports,
+            api_surface_severity,
+        )));
+
+        let barrel_severity = parse_severity(&self.rules.severity.max_barrel_reexports);
+        registry.register(Box::new(barrels::BarrelRule::new(
+            self.rules.max_barrel_reexports,
+            barrel_severity,
+        )));
+
+        let no_cycles_severity = parse_severity(&self.rules.severity.no_cycles);
+        registry.register(Box::new(no_cycles::NoCyclesRule::new(no_cycles_severity)));
+
+        let root = self.root.clone().unwrap_or_else(|| PathBuf::from("."));
+
+        // `load` rejects invalid layers, so only a `Config` built in code reaches this
+        // with one; it gets no layer rule
+        if let Ok(layers) = self.architecture.layers(&root) {
+            let layers_severity = parse_severity(&self.rules.severity.layers);
+            registry.register(Box::new(layers::LayerRule::new(
+                layers,
+                self.architecture
+                    .forbidden
+                    .iter()
+                    .map(|f| (f.from.clone(), f.to.clone()))
+                    .collect(),
+                layers_severity,
+            )));
+        }
+
+        if self.rules.stable_dependencies {
+            if let Ok(components) = self.architecture.components(&root) {
+                let stable_severity = parse_severity(&self.rules.severity.stable_dependencies);
+                registry.register(Box::new(stable_dependencies::StableDependenciesRule::new(
+                    components,
+                    stable_severity,
+                )));
+            }
+        }
+
+        if self.rules.centrality {
+            registry.register(Box::new(centrality::CentralityRule::new()));
+        }
+
+        if self.rules.unused_exports {
+            if let Ok(entry_points) = self.reachability.entry_points() {
+                let unused_severity = parse_severity(&self.rules.severity.unused_exports);
+                registry.register(Box::new(unused_exports::UnusedExportsRule::new(
+                    entry_points,
+                    root.clone(),
+                    unused_severity,
+                )));
+            }
+        }
+
+        if self.rules.broken_imports {
+            let broken_severity = parse_severity(&self.rules.severity.broken_imports);
+            registry.register(Box::new(broken_imports::BrokenImportsRule::new(
+                broken_severity,
+            )));
+        }
+
         registry
     }
 }
 
+impl ArchitectureConfig {
+    /// Compiles the layer globs, relative to the project `root`, checking that `forbidden`
+    /// only names declared layers
+    pub fn layers(&self, root: &Path) -> Result<Vec<layers::Layer>> {
+        for forbidden in &self.forbidden {
+            for name in [&forbidden.from, &forbidden.to] {
+                if !self.layers.iter().any(|layer| &layer.name == name) {
+                    anyhow::bail!("Unknown layer '{}' in architecture.forbidden", name);
+                }
+            }
+        }
+        self.layers
+            .iter()
+            .map(|layer| Ok(layers::Layer::new(&layer.name, &layer.paths)?.with_root(root)))
+            .collect()
+    }
+
+    /// Components for package metrics: the layers when declared, else directories
+    pub fn components(&self, root: &Path) -> Result<Components> {
+        let layers = self.layers(root)?;
+        Ok(if layers.is_empty() {
+            Components::Directories
+        } else {
+            Components::Layers(layers)
+        })
+    }
+}
+
+impl ReachabilityConfig {
+    /// Compiles the entry point globs
+    pub fn entry_points(&self) -> Result<EntryPoints> {
+        EntryPoints::new(&self.entry_points)
+    }
+}
+
 fn parse_severity(s: &str) -> Severity {
     match s.to_lowercase().as_str() {
         "error" => Severity::Error,
@@ -193,6 +440,44 @@ mod tests {
         assert_eq!(config.rules.max_function_loc, 40);
     }
 
+    #[test]
+    fn test_architecture_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [architecture]
+            layers = [
+                { name = "ui", paths = ["src/ui/**"] },
+                { name = "infra", paths = ["src/infra/**"] },
+            ]
+            forbidden = [{ from = "ui", to = "infra" }]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.architecture.layers(Path::new(".")).unwrap().len(), 2);
+        assert_eq!(config.rules.severity.layers, "Error");
+
+        let mut unknown = config.architecture.clone();
+        unknown.forbidden[0].to = "db".to_string();
+        assert!(unknown.layers(Path::new(".")).is_err());
+    }
+
+    #[test]
+    fn test_reachability_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [reachability]
+            entry_points = ["src/main.ts", "scripts/*.ts"]
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.reachability.entry_points.len(), 2);
+        assert!(config.reachability.entry_points().is_ok());
+
+        let mut invalid = config.reachability.clone();
+        invalid.entry_points.push("src/[".to_string());
+        assert!(invalid.entry_points().is_err());
+    }
+
     #[test]
     fn test_parse_severity() {
         assert!(matches!(parse_severity("Error"), Severity::Error));
@@ -200,3 +485,7 @@ mod tests {
         assert!(matches!(parse_severity("info"), Severity::Info));
     }
 }
+
+#[cfg(test)]
+#[path = "config_test.rs"]
+mod config_test;
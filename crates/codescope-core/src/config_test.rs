@@ -0,0 +1,64 @@
+#[cfg(test)]
+mod tests {
+    use crate::config::Config;
+    use crate::test_fixtures::write;
+
+    #[test]
+    fn test_load_or_default() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "valid.toml",
+            r#"
+[rules]
+max_file_loc = 1
+
+[architecture]
+layers = [{ name = "ui", paths = ["src/ui/**"] }, { name = "infra", paths = ["src/infra/**"] }]
+forbidden = [{ from = "ui", to = "infra" }]
+"#,
+        );
+        let config = Config::load_or_default(Some(&root.join("valid.toml"))).unwrap();
+        assert_eq!(config.rules.max_file_loc, 1);
+        assert_eq!(
+            Config::load_or_default(None).unwrap().rules.max_file_loc,
+            300
+        );
+    }
+
+    #[test]
+    fn test_load_or_default_rejects_invalid_config() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "typo.toml",
+            r#"
+[rules]
+max_file_loc = 1
+
+[architecture]
+layers = [{ name = "ui", paths = ["src/ui/**"] }, { name = "infra", paths = ["src/infra/**"] }]
+forbidden = [{ from = "ui", to = "infrx" }]
+"#,
+        );
+        write(
+            root,
+            "glob.toml",
+            "[reachability]\nentry_points = [\"src/[main.ts\"]\n",
+        );
+        write(root, "syntax.toml", "[rules\n");
+
+        // A broken file fails the run instead of silently falling back to the defaults
+        for name in ["typo.toml", "glob.toml", "syntax.toml", "missing.toml"] {
+            assert!(
+                Config::load_or_default(Some(&root.join(name))).is_err(),
+                "{}",
+                name
+            );
+        }
+        let error = Config::load_or_default(Some(&root.join("typo.toml"))).unwrap_err();
+        assert!(format!("{:#}", error).contains("Unknown layer 'infrx'"));
+    }
+}
@@ -0,0 +1,264 @@
+use crate::types::{BindingKind, DepEdge, Export, ImportKind, ModuleIR};
+use std::collections::{HashMap, HashSet};
+
+/// Exports of every linked module, with the re-exports (`export ... from`) that forward
+/// names from one module to another. Built after `DependencyGraph::from_modules`, which
+/// points internal edges at module paths.
+pub struct ExportIndex<'a> {
+    modules: HashMap<&'a str, &'a ModuleIR>,
+}
+
+/// Where an exported name is declared
+pub struct Origin<'a> {
+    pub path: &'a str,
+    /// Name declared there, `*` for a namespace re-export (`export * as ns from`)
+    pub name: String,
+    /// Modules forwarding the name, starting with the one asked about
+    pub via: Vec<&'a str>,
+}
+
+/// A use of a module's exports, as seen by its importers
+enum Use<'a> {
+    Name(&'a str, String),
+    All(&'a str),
+}
+
+impl<'a> ExportIndex<'a> {
+    pub fn new(modules: &'a [ModuleIR]) -> Self {
+        Self {
+            modules: modules.iter().map(|m| (m.path.as_str(), m)).collect(),
+        }
+    }
+
+    /// Internal edges of `path` with the module they point at
+    fn internal_edges(&self, path: &str) -> impl Iterator<Item = (&'a DepEdge, &'a str)> + '_ {
+        self.modules
+            .get(path)
+            .into_iter()
+            .flat_map(|module| &module.outgoing)
+            .filter_map(|edge| {
+                let target = self.modules.get(edge.target.as_deref()?)?;
+                Some((edge, target.path.as_str()))
+            })
+    }
+
+    fn reexports(&self, path: &str) -> impl Iterator<Item = (&'a DepEdge, &'a str)> + '_ {
+        self.internal_edges(path)
+            .filter(|(edge, _)| edge.import_kind == Some(ImportKind::ReExport))
+    }
+
+    fn declares(&self, path: &str, name: &str) -> bool {
+        self.modules
+            .get(path)
+            .is_some_and(|module| module.exports.iter().any(|e| e.name == name))
+    }
+
+    /// Whether `path` exports `name`, itself or through re-exports. Modules whose exports
+    /// are not fully known are assumed to export it: files outside the index, files where
+    /// no export was found, files assigning `module.exports` or `export =` a value other
+    /// than an object literal, and `export *` from a package or an unresolved file.
+    pub fn exports_name(&self, path: &str, name: &str) -> bool {
+        self.find_export(path, name, &mut HashSet::new())
+    }
+
+    fn find_export(&self, path: &str, name: &str, visited: &mut HashSet<(String, String)>) -> bool {
+        let Some(module) = self.modules.get(path) else {
+            return true;
+        };
+        if !visited.insert((path.to_string(), name.to_string())) {
+            return false;
+        }
+
+        let reexports: Vec<&DepEdge> = module
+            .outgoing
+            .iter()
+            .filter(|edge| edge.import_kind == Some(ImportKind::ReExport))
+            .collect();
+        if module.dynamic_exports || (reexports.is_empty() && module.exports.is_empty()) {
+            return true;
+        }
+        if module.exports.iter().any(|e| e.name == name) {
+            return true;
+        }
+
+        for edge in reexports {
+            let target = edge
+                .target
+                .as_deref()
+                .filter(|t| self.modules.contains_key(t));
+            for binding in &edge.bindings {
+                let forwarded = match binding.kind {
+                    BindingKind::Namespace if binding.local == "*" => {
+                        (name != "default").then_some(name)
+                    }
+                    BindingKind::Namespace if binding.local == name => return true,
+                    BindingKind::Default | BindingKind::Named if binding.local == name => {
+                        Some(binding.imported.as_str())
+                    }
+                    _ => None,
+                };
+                let Some(imported) = forwarded else {
+                    continue;
+                };
+                match target {
+                    Some(target) if !self.find_export(target, imported, visited) => {}
+                    _ => return true,
+                }
+            }
+        }
+        false
+    }
+
+    /// Module declaring the `name` exported by `path`, following re-exports. `None` when
+    /// the name is not found, or is forwarded from a file outside the index.
+    pub fn origin(&self, path: &str, name: &str) -> Option<Origin<'a>> {
+        self.trace(path, name, &mut HashSet::new())
+    }
+
+    fn trace(
+        &self,
+        path: &str,
+        name: &str,
+        visited: &mut HashSet<(String, String)>,
+    ) -> Option<Origin<'a>> {
+        let module = *self.modules.get(path)?;
+        if !visited.insert((path.to_string(), name.to_string())) {
+            return None;
+        }
+        if self.declares(path, name) {
+            return Some(Origin {
+                path: &module.path,
+                name: name.to_string(),
+                via: vec![],
+            });
+        }
+
+        for (edge, target) in self.reexports(path) {
+            for binding in &edge.bindings {
+                let forwarded = match binding.kind {
+                    BindingKind::Namespace if binding.local == "*" => {
+                        (name != "default").then(|| name.to_string())
+                    }
+                    BindingKind::Namespace if binding.local == name => {
+                        return Some(Origin {
+                            path: target,
+                            name: "*".to_string(),
+                            via: vec![&module.path],
+                        });
+                    }
+                    BindingKind::Default | BindingKind::Named if binding.local == name => {
+                        Some(binding.imported.clone())
+                    }
+                    _ => None,
+                };
+                let Some(imported) = forwarded else {
+                    continue;
+                };
+                if let Some(mut origin) = self.trace(target, &imported, visited) {
+                    origin.via.insert(0, &module.path);
+                    return Some(origin);
+                }
+            }
+        }
+        None
+    }
+
+    /// Exports that no module imports, following re-export chains. Every export of the
+    /// `entry_points` is public API and counts as used, as does every export of a module
+    /// imported whole (`import * as ns`, `require`, dynamic `import()`).
+    pub fn unused_exports(&self, entry_points: &[&str]) -> Vec<(&'a str, &'a Export)> {
+        let mut queue: Vec<Use> = entry_points
+            .iter()
+            .filter_map(|path| self.modules.get(path))
+            .map(|module| Use::All(module.path.as_str()))
+            .collect();
+        for module in self.modules.values() {
+            for (edge, target) in self.internal_edges(&module.path) {
+                queue.extend(imported_uses(edge, target));
+            }
+        }
+
+        let mut used_all: HashSet<&str> = HashSet::new();
+        let mut used: HashSet<(&str, String)> = HashSet::new();
+        while let Some(next) = queue.pop() {
+            match next {
+                Use::All(path) => {
+                    if !used_all.insert(path) {
+                        continue;
+                    }
+                    for (edge, target) in self.reexports(path) {
+                        for binding in &edge.bindings {
+                            queue.push(match binding.kind {
+                                BindingKind::Namespace => Use::All(target),
+                                _ => Use::Name(target, binding.imported.clone()),
+                            });
+                        }
+                    }
+                }
+                Use::Name(path, name) => {
+                    if !used.insert((path, name.clone())) || self.declares(path, &name) {
+                        continue;
+                    }
+                    for (edge, target) in self.reexports(path) {
+                        for binding in &edge.bindings {
+                            match binding.kind {
+                                // `export * from`: every name but the default
+                                BindingKind::Namespace
+                                    if binding.local == "*" && name != "default" =>
+                                {
+                                    queue.push(Use::Name(target, name.clone()));
+                                }
+                                // `export * as ns from`
+                                BindingKind::Namespace if binding.local == name => {
+                                    queue.push(Use::All(target));
+                                }
+                                BindingKind::Default | BindingKind::Named
+                                    if binding.local == name =>
+                                {
+                                    queue.push(Use::Name(target, binding.imported.clone()));
+                                }
+                                _ => {}
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        let mut unused: Vec<(&str, &Export)> = self
+            .modules
+            .values()
+            .filter(|module| !used_all.contains(module.path.as_str()))
+            .flat_map(|module| {
+                module
+                    .exports
+                    .iter()
+                    .map(move |export| (module.path.as_str(), export))
+            })
+            .filter(|(path, export)| !used.contains(&(*path, export.name.clone())))
+            .collect();
+        unused.sort_by(|a, b| a.0.cmp(b.0).then_with(|| a.1.name.cmp(&b.1.name)));
+        unused
+    }
+}
+
+/// What an import (not a re-export) of `target` uses
+fn imported_uses<'a>(edge: &DepEdge, target: &'a str) -> Vec<Use<'a>> {
+    match edge.import_kind {
+        Some(ImportKind::ReExport) | Some(ImportKind::SideEffect) => vec![],
+        Some(ImportKind::Static) => edge
+            .bindings
+            .iter()
+            .map(|binding| match binding.kind {
+                BindingKind::Namespace => Use::All(target),
+                _ => Use::Name(target, binding.imported.clone()),
+            })
+            .collect(),
+        // `require`, `import()` and edges of unknown kind may touch any export
+        _ => vec![Use::All(target)],
+    }
+}
+
+#[cfg(test)]
+#[path = "exports_test.rs"]
+mod exports_test;
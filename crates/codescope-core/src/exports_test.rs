@@ -0,0 +1,254 @@
+#[cfg(test)]
+mod tests {
+    use crate::exports::ExportIndex;
+    use crate::graph::DependencyGraph;
+    use crate::reachability::EntryPoints;
+    use crate::rules::broken_imports::BrokenImportsRule;
+    use crate::rules::unused_exports::UnusedExportsRule;
+    use crate::rules::QualityRule;
+    use crate::test_fixtures::{import_edge, line_span, module_exporting, symbol};
+    use crate::types::{ImportKind, ModuleIR, ResolutionKind, Severity, SymbolKind};
+
+    fn unused(modules: &[ModuleIR], entry_points: &[&str]) -> Vec<(String, String)> {
+        ExportIndex::new(modules)
+            .unused_exports(entry_points)
+            .into_iter()
+            .map(|(path, export)| (path.to_string(), export.name.clone()))
+            .collect()
+    }
+
+    fn pairs(expected: &[(&str, &str)]) -> Vec<(String, String)> {
+        expected
+            .iter()
+            .map(|(path, name)| (path.to_string(), name.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn test_unused_exports_through_reexports() {
+        use ImportKind::{ReExport, Static};
+        let modules = vec![
+            module_exporting(
+                "main.ts",
+                &["run"],
+                vec![
+                    import_edge("lib/a.ts", Static, &[("a", "a")]),
+                    import_edge("lib/ns.ts", Static, &[("*", "ns")]),
+                    import_edge(
+                        "lib/index.ts",
+                        Static,
+                        &[("b", "b"), ("c", "c"), ("tools", "t")],
+                    ),
+                ],
+            ),
+            module_exporting(
+                "lib/index.ts",
+                &["version"],
+                vec![
+                    import_edge("lib/b.ts", ReExport, &[("*", "*")]),
+                    import_edge("lib/c.ts", ReExport, &[("c", "c"), ("d", "d")]),
+                    import_edge("lib/tools.ts", ReExport, &[("*", "tools")]),
+                ],
+            ),
+            module_exporting("lib/a.ts", &["a", "helper"], vec![]),
+            module_exporting("lib/b.ts", &["b", "bUnused", "default"], vec![]),
+            module_exporting("lib/c.ts", &["c", "d"], vec![]),
+            module_exporting("lib/ns.ts", &["x", "y"], vec![]),
+            module_exporting("lib/tools.ts", &["hammer"], vec![]),
+        ];
+
+        // `export *` does not forward the default export
+        assert_eq!(
+            unused(&modules, &["main.ts"]),
+            pairs(&[
+                ("lib/a.ts", "helper"),
+                ("lib/b.ts", "bUnused"),
+                ("lib/b.ts", "default"),
+                ("lib/c.ts", "d"),
+                ("lib/index.ts", "version"),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_entry_points_and_whole_module_imports() {
+        let modules = vec![
+            module_exporting(
+                "index.ts",
+                &["api"],
+                vec![import_edge("lazy.ts", ImportKind::Dynamic, &[])],
+            ),
+            module_exporting("lazy.ts", &["load"], vec![]),
+            module_exporting(
+                "cli.ts",
+                &["main"],
+                vec![import_edge("index.ts", ImportKind::SideEffect, &[])],
+            ),
+        ];
+
+        assert_eq!(
+            unused(&modules, &[]),
+            pairs(&[("cli.ts", "main"), ("index.ts", "api")])
+        );
+        assert_eq!(unused(&modules, &["index.ts", "cli.ts"]), pairs(&[]));
+    }
+
+    #[test]
+    fn test_reexport_cycle() {
+        use ImportKind::{ReExport, Static};
+        let modules = vec![
+            module_exporting(
+                "main.ts",
+                &[],
+                vec![import_edge("a.ts", Static, &[("z", "z")])],
+            ),
+            module_exporting(
+                "a.ts",
+                &["a"],
+                vec![import_edge("b.ts", ReExport, &[("*", "*")])],
+            ),
+            module_exporting(
+                "b.ts",
+                &["b"],
+                vec![import_edge("a.ts", ReExport, &[("*", "*")])],
+            ),
+        ];
+
+        assert_eq!(
+            unused(&modules, &["main.ts"]),
+            pairs(&[("a.ts", "a"), ("b.ts", "b")])
+        );
+    }
+
+    #[test]
+    fn test_unused_exports_rule() {
+        let mut modules = vec![
+            module_exporting(
+                "src/main.ts",
+                &[],
+                vec![import_edge(
+                    "src/shapes.ts",
+                    ImportKind::Static,
+                    &[("Circle", "Circle")],
+                )],
+            ),
+            module_exporting("src/shapes.ts", &["Circle", "Square", "UNIT"], vec![]),
+        ];
+        modules[1].symbols = vec![
+            symbol("Circle", SymbolKind::Class),
+            symbol("Square", SymbolKind::Class),
+        ];
+        let graph = DependencyGraph::from_modules(&mut modules);
+
+        let dir = tempfile::tempdir().unwrap();
+        let entry_points = EntryPoints::new(&["src/main.ts".to_string()]).unwrap();
+        let rule =
+            UnusedExportsRule::new(entry_points, dir.path().to_path_buf(), Severity::Warning);
+        let metrics = rule.check_modules(&graph, &modules);
+
+        let found: Vec<(&str, Option<&str>, &str)> = metrics
+            .iter()
+            .map(|m| (m.path.as_str(), m.symbol.as_deref(), m.metric.name.as_str()))
+            .collect();
+        assert_eq!(
+            found,
+            vec![
+                ("src/shapes.ts", Some("Square"), "unused_export"),
+                ("src/shapes.ts", Some("UNIT"), "unused_export"),
+                ("src/shapes.ts", None, "unused_export_count"),
+            ]
+        );
+        assert_eq!(
+            metrics[2].metric.message.as_deref(),
+            Some("2 exports are never imported: Square, UNIT")
+        );
+    }
+
+    #[test]
+    fn test_exports_name() {
+        use ImportKind::ReExport;
+        let modules = vec![
+            module_exporting(
+                "index.ts",
+                &["version"],
+                vec![
+                    import_edge("b.ts", ReExport, &[("*", "*")]),
+                    import_edge("c.ts", ReExport, &[("c", "renamed"), ("default", "C")]),
+                ],
+            ),
+            module_exporting("b.ts", &["b", "default"], vec![]),
+            module_exporting("c.ts", &["c", "default"], vec![]),
+            module_exporting("d.ts", &["d"], vec![]),
+            module_exporting("cjs.js", &[], vec![]),
+            module_exporting(
+                "vendor.ts",
+                &["v"],
+                vec![import_edge("lodash", ReExport, &[("*", "*")])],
+            ),
+        ];
+        let index = ExportIndex::new(&modules);
+
+        assert!(index.exports_name("d.ts", "d"));
+        assert!(!index.exports_name("d.ts", "missing"));
+        assert!(index.exports_name("index.ts", "b"));
+        assert!(index.exports_name("index.ts", "renamed"));
+        assert!(index.exports_name("index.ts", "C"));
+        assert!(!index.exports_name("index.ts", "c"));
+        // Files without known exports, files outside the index and `export *` from a
+        // package may export anything
+        assert!(index.exports_name("cjs.js", "anything"));
+        assert!(index.exports_name("styles.css", "anything"));
+        assert!(index.exports_name("vendor.ts", "anything"));
+    }
+
+    #[test]
+    fn test_exports_name_of_dynamic_exports() {
+        // `const api = { get() {} }; module.exports = api;`
+        let mut api = module_exporting("api.js", &["default"], vec![]);
+        let modules = vec![api.clone()];
+        assert!(!ExportIndex::new(&modules).exports_name("api.js", "get"));
+
+        api.dynamic_exports = true;
+        let modules = vec![api];
+        let index = ExportIndex::new(&modules);
+        assert!(index.exports_name("api.js", "get"));
+        assert!(index.exports_name("api.js", "default"));
+    }
+
+    #[test]
+    fn test_broken_imports_rule() {
+        let mut stale = import_edge(
+            "src/date.ts",
+            ImportKind::Static,
+            &[("parseDate", "parseDate"), ("formatDate", "formatDate")],
+        );
+        stale.span = Some(line_span(2));
+        stale.resolution = Some(ResolutionKind::Internal);
+        let mut missing = import_edge("./old/util", ImportKind::Static, &[("x", "x")]);
+        missing.specifier = Some("./old/util".to_string());
+        missing.span = Some(line_span(3));
+        missing.resolution = Some(ResolutionKind::Unresolved);
+
+        let mut modules = vec![
+            module_exporting("src/app.ts", &[], vec![stale, missing]),
+            module_exporting("src/date.ts", &["formatDate"], vec![]),
+        ];
+        let graph = DependencyGraph::from_modules(&mut modules);
+        let rule = BrokenImportsRule::new(Severity::Error);
+
+        let unresolved = rule.check_module(&modules[0]);
+        assert_eq!(unresolved.len(), 1);
+        assert_eq!(
+            unresolved[0].message.as_deref(),
+            Some("Broken import: './old/util' resolves to no file (src/app.ts:3)")
+        );
+
+        let metrics = rule.check_modules(&graph, &modules);
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].path, "src/app.ts");
+        assert_eq!(
+            metrics[0].metric.message.as_deref(),
+            Some("Broken import: src/date.ts does not export 'parseDate' (src/app.ts:2)")
+        );
+    }
+}
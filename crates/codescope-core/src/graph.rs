@@ -1,10 +1,18 @@
-use crate::types::{DepEdge, ModuleIR};
+use crate::barrels;
+use crate::resolve::normalize;
+use crate::types::{Cycle, CycleStep, DepEdge, ModuleIR, RealEdge, ResolutionKind};
+use petgraph::algo::tarjan_scc;
 use petgraph::graph::{DiGraph, NodeIndex};
-use std::collections::HashMap;
+use petgraph::visit::{EdgeFiltered, EdgeRef};
+use std::collections::hash_map::Entry;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::path::Path;
 
 pub struct DependencyGraph {
     graph: DiGraph<String, DepEdge>,
     path_to_node: HashMap<String, NodeIndex>,
+    /// Imports through re-exports, linked to the modules declaring the names
+    real_edges: Vec<RealEdge>,
 }
 
 impl DependencyGraph {
@@ -12,9 +20,58 @@ impl DependencyGraph {
         Self {
             graph: DiGraph::new(),
             path_to_node: HashMap::new(),
+            real_edges: Vec::new(),
         }
     }
 
+    /// Builds the graph over `modules` and links them: outgoing edges get the module as
+    /// `source` and, when they resolve to another module, its `path` as `target`;
+    /// `incoming` is filled with the edges pointing at each module. Imports of names
+    /// re-exported by their target also get a real edge to the declaring module.
+    pub fn from_modules(modules: &mut [ModuleIR]) -> Self {
+        let mut graph = Self::new();
+        let mut paths: HashMap<_, String> = HashMap::new();
+        for module in modules.iter() {
+            graph.add_module(module);
+            paths.insert(normalize(Path::new(&module.path)), module.path.clone());
+        }
+
+        for module in modules.iter_mut() {
+            for edge in &mut module.outgoing {
+                edge.source = Some(module.path.clone());
+                if edge.resolution == Some(ResolutionKind::External) {
+                    continue;
+                }
+                let target = edge
+                    .target
+                    .as_ref()
+                    .and_then(|target| paths.get(&normalize(Path::new(target))));
+                if let Some(target) = target {
+                    edge.target = Some(target.clone());
+                    graph.add_edge(&module.path, target, edge.clone());
+                }
+            }
+        }
+
+        for module in modules.iter_mut() {
+            let mut incoming: Vec<DepEdge> = graph
+                .get_incoming(&module.path)
+                .into_iter()
+                .cloned()
+                .collect();
+            incoming.sort_by_key(|edge| {
+                (
+                    edge.source.clone(),
+                    edge.span.as_ref().map(|s| s.start_byte),
+                )
+            });
+            module.incoming = incoming;
+        }
+
+        graph.real_edges = barrels::real_edges(modules);
+        graph
+    }
+
     pub fn add_module(&mut self, module: &ModuleIR) -> NodeIndex {
         if let Some(&node) = self.path_to_node.get(&module.path) {
             return node;
@@ -56,23 +113,329 @@ impl DependencyGraph {
         }
     }
 
+    /// Number of distinct modules importing `path`
     pub fn fan_in(&self, path: &str) -> u32 {
         if let Some(&node) = self.path_to_node.get(path) {
             self.graph
-                .edges_directed(node, petgraph::Direction::Incoming)
-                .count() as u32
+                .neighbors_directed(node, petgraph::Direction::Incoming)
+                .collect::<HashSet<_>>()
+                .len() as u32
         } else {
             0
         }
     }
 
+    /// Number of distinct modules `path` imports
     pub fn fan_out(&self, path: &str) -> u32 {
         if let Some(&node) = self.path_to_node.get(path) {
-            self.graph.edges(node).count() as u32
+            self.graph.neighbors(node).collect::<HashSet<_>>().len() as u32
         } else {
             0
         }
     }
+
+    /// Import cycles, one per strongly connected component. Type-only imports are
+    /// erased at compile time and cannot cause init-order problems, so they are ignored.
+    pub fn cycles(&self) -> Vec<Cycle> {
+        let runtime = EdgeFiltered::from_fn(&self.graph, |edge| !edge.weight().type_only);
+
+        let mut cycles: Vec<Cycle> = tarjan_scc(&runtime)
+            .into_iter()
+            .filter(|component| {
+                component.len() > 1
+                    || self
+                        .graph
+                        .edges(component[0])
+                        .any(|edge| !edge.weight().type_only && edge.target() == component[0])
+            })
+            .filter_map(|component| self.cycle_through(&component))
+            .collect();
+        cycles.sort_by(|a, b| a.modules.cmp(&b.modules));
+        cycles
+    }
+
+    /// Shortest loop from the first module (by path) back to itself within `component`
+    fn cycle_through(&self, component: &[NodeIndex]) -> Option<Cycle> {
+        let members: HashSet<NodeIndex> = component.iter().copied().collect();
+        let start = *component.iter().min_by_key(|&&node| &self.graph[node])?;
+
+        // Breadth-first search, remembering the import taken to reach each module
+        let mut reached_by: HashMap<NodeIndex, (NodeIndex, &DepEdge)> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        'search: while let Some(node) = queue.pop_front() {
+            let mut next: Vec<(NodeIndex, &DepEdge)> = self
+                .graph
+                .edges(node)
+                .filter(|edge| !edge.weight().type_only && members.contains(&edge.target()))
+                .map(|edge| (edge.target(), edge.weight()))
+                .collect();
+            // Deterministic: earliest import site first
+            next.sort_by_key(|(_, edge)| edge.span.as_ref().map(|s| s.start_byte));
+
+            for (target, edge) in next {
+                if target == start {
+                    reached_by.insert(start, (node, edge));
+                    break 'search;
+                }
+                if let Entry::Vacant(entry) = reached_by.entry(target) {
+                    entry.insert((node, edge));
+                    queue.push_back(target);
+                }
+            }
+        }
+
+        let mut path = Vec::new();
+        let mut node = start;
+        loop {
+            let &(previous, edge) = reached_by.get(&node)?;
+            path.push(CycleStep {
+                source: self.graph[previous].clone(),
+                target: self.graph[node].clone(),
+                span: edge.span,
+            });
+            node = previous;
+            if node == start {
+                break;
+            }
+        }
+        path.reverse();
+
+        let mut modules: Vec<String> = component
+            .iter()
+            .map(|&node| self.graph[node].clone())
+            .collect();
+        modules.sort();
+        Some(Cycle { modules, path })
+    }
+
+    /// Modules that import any of `paths`, directly or transitively, with their distance
+    /// in import hops, sorted by distance then path. The `paths` themselves are left out.
+    pub fn dependents(&self, paths: &[&str]) -> Vec<(String, usize)> {
+        let mut distances: HashMap<NodeIndex, usize> = HashMap::new();
+        let mut queue = VecDeque::new();
+        for path in paths {
+            if let Some(&node) = self.path_to_node.get(*path) {
+                distances.insert(node, 0);
+                queue.push_back(node);
+            }
+        }
+
+        while let Some(node) = queue.pop_front() {
+            let distance = distances[&node];
+            for importer in self
+                .graph
+                .neighbors_directed(node, petgraph::Direction::Incoming)
+            {
+                if let Entry::Vacant(entry) = distances.entry(importer) {
+                    entry.insert(distance + 1);
+                    queue.push_back(importer);
+                }
+            }
+        }
+
+        let mut dependents: Vec<(String, usize)> = distances
+            .into_iter()
+            .filter(|&(_, distance)| distance > 0)
+            .map(|(node, distance)| (self.graph[node].clone(), distance))
+            .collect();
+        dependents.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+        dependents
+    }
+
+    /// Modules imported by any of `paths`, directly or transitively, the `paths` included.
+    /// Type-only imports are followed: a file used only for its types is still in use.
+    pub fn reachable(&self, paths: &[&str]) -> HashSet<&str> {
+        let mut visited: HashSet<NodeIndex> = HashSet::new();
+        let mut queue: VecDeque<NodeIndex> = paths
+            .iter()
+            .filter_map(|path| self.path_to_node.get(*path).copied())
+            .collect();
+        while let Some(node) = queue.pop_front() {
+            if visited.insert(node) {
+                queue.extend(self.graph.neighbors(node));
+            }
+        }
+
+        visited
+            .into_iter()
+            .map(|node| self.graph[node].as_str())
+            .collect()
+    }
+
+    /// Shortest chain of imports from `from` to `to`, taking the earliest import site on ties.
+    /// With `runtime_only`, type-only imports are not followed.
+    pub fn shortest_path(
+        &self,
+        from: &str,
+        to: &str,
+        runtime_only: bool,
+    ) -> Option<Vec<CycleStep>> {
+        let start = *self.path_to_node.get(from)?;
+        let goal = *self.path_to_node.get(to)?;
+
+        let mut reached_by: HashMap<NodeIndex, (NodeIndex, &DepEdge)> = HashMap::new();
+        let mut queue = VecDeque::from([start]);
+        while let Some(node) = queue.pop_front() {
+            if node == goal {
+                break;
+            }
+            for (target, edge) in self.imports_of(node, runtime_only) {
+                if target != start {
+                    if let Entry::Vacant(entry) = reached_by.entry(target) {
+                        entry.insert((node, edge));
+                        queue.push_back(target);
+                    }
+                }
+            }
+        }
+
+        let mut path = Vec::new();
+        let mut node = goal;
+        while node != start {
+            let &(previous, edge) = reached_by.get(&node)?;
+            path.push(self.step(previous, node, edge));
+            node = previous;
+        }
+        path.reverse();
+        Some(path)
+    }
+
+    /// Simple import chains from `from` to `to` of at most `max_hops` imports, shortest first.
+    /// Chains are searched one length at a time, so the `limit` kept are the shortest.
+    pub fn all_paths(
+        &self,
+        from: &str,
+        to: &str,
+        max_hops: usize,
+        limit: usize,
+        runtime_only: bool,
+    ) -> Vec<Vec<CycleStep>> {
+        let (Some(&start), Some(&goal)) = (self.path_to_node.get(from), self.path_to_node.get(to))
+        else {
+            return vec![];
+        };
+
+        let mut search = PathSearch {
+            goal,
+            hops: 0,
+            limit,
+            runtime_only,
+            visited: HashSet::from([start]),
+            current: Vec::new(),
+            paths: Vec::new(),
+        };
+        for hops in 1..=max_hops {
+            if search.paths.len() >= limit {
+                break;
+            }
+            search.hops = hops;
+            self.collect_paths(start, &mut search);
+        }
+        search.paths
+    }
+
+    /// Depth-first walk extending `search.current` from `node`, keeping the chains of
+    /// exactly `search.hops` imports
+    fn collect_paths(&self, node: NodeIndex, search: &mut PathSearch) {
+        for (target, edge) in self.imports_of(node, search.runtime_only) {
+            if search.paths.len() >= search.limit {
+                return;
+            }
+            let last = search.current.len() + 1 == search.hops;
+            if target == search.goal {
+                if last {
+                    let mut path = search.current.clone();
+                    path.push(self.step(node, target, edge));
+                    search.paths.push(path);
+                }
+                continue;
+            }
+            if last || !search.visited.insert(target) {
+                continue;
+            }
+            search.current.push(self.step(node, target, edge));
+            self.collect_paths(target, search);
+            search.current.pop();
+            search.visited.remove(&target);
+        }
+    }
+
+    /// Modules imported by `node`, one entry per module at its earliest import site
+    fn imports_of(&self, node: NodeIndex, runtime_only: bool) -> Vec<(NodeIndex, &DepEdge)> {
+        let mut imports: Vec<(NodeIndex, &DepEdge)> = self
+            .graph
+            .edges(node)
+            .filter(|edge| !(runtime_only && edge.weight().type_only))
+            .map(|edge| (edge.target(), edge.weight()))
+            .collect();
+        imports.sort_by_key(|(target, edge)| {
+            (
+                edge.span.as_ref().map(|s| s.start_byte),
+                self.graph[*target].clone(),
+            )
+        });
+        let mut seen = HashSet::new();
+        imports.retain(|(target, _)| seen.insert(*target));
+        imports
+    }
+
+    fn step(&self, source: NodeIndex, target: NodeIndex, edge: &DepEdge) -> CycleStep {
+        CycleStep {
+            source: self.graph[source].clone(),
+            target: self.graph[target].clone(),
+            span: edge.span,
+        }
+    }
+
+    /// Module whose path is `path` once both are normalized
+    pub fn find(&self, path: &Path) -> Option<&str> {
+        let path = normalize(path);
+        self.modules()
+            .find(|module| normalize(Path::new(module)) == path)
+    }
+
+    /// Module paths in insertion order
+    pub fn modules(&self) -> impl Iterator<Item = &str> {
+        self.graph.node_weights().map(String::as_str)
+    }
+
+    /// Every import edge as `(source, target, edge)`
+    pub fn edges(&self) -> impl Iterator<Item = (&str, &str, &DepEdge)> {
+        self.graph.edge_references().map(|edge| {
+            (
+                self.graph[edge.source()].as_str(),
+                self.graph[edge.target()].as_str(),
+                edge.weight(),
+            )
+        })
+    }
+
+    /// Real edges behind imports of barrels; the graph keeps the apparent edges to the
+    /// barrels themselves
+    pub fn real_edges(&self) -> &[RealEdge] {
+        &self.real_edges
+    }
+
+    pub fn module_count(&self) -> usize {
+        self.graph.node_count()
+    }
+
+    pub fn edge_count(&self) -> usize {
+        self.graph.edge_count()
+    }
+}
+
+/// State of an `all_paths` search
+struct PathSearch {
+    goal: NodeIndex,
+    /// Chain length searched in the current pass
+    hops: usize,
+    limit: usize,
+    runtime_only: bool,
+    /// Modules on the current chain, so chains stay simple
+    visited: HashSet<NodeIndex>,
+    current: Vec<CycleStep>,
+    paths: Vec<Vec<CycleStep>>,
 }
 
 impl Default for DependencyGraph {
@@ -1,26 +1,13 @@
 #[cfg(test)]
 mod tests {
     use crate::graph::DependencyGraph;
-    use crate::types::{DepEdge, DepKind, ModuleIR};
-
-    fn create_test_module(path: &str) -> ModuleIR {
-        ModuleIR {
-            path: path.to_string(),
-            language: Some("typescript".to_string()),
-            loc: 100,
-            comment_lines: 10,
-            blank_lines: 5,
-            symbols: vec![],
-            metrics: vec![],
-            outgoing: vec![],
-            incoming: vec![],
-        }
-    }
+    use crate::test_fixtures::{edge, module};
+    use crate::types::{DepEdge, ResolutionKind};
 
     #[test]
     fn test_add_module() {
         let mut graph = DependencyGraph::new();
-        let module = create_test_module("test.ts");
+        let module = module("test.ts");
 
         graph.add_module(&module);
         assert_eq!(graph.fan_in("test.ts"), 0);
@@ -31,19 +18,13 @@ mod tests {
     fn test_add_edge() {
         let mut graph = DependencyGraph::new();
 
-        let mod_a = create_test_module("a.ts");
-        let mod_b = create_test_module("b.ts");
+        let mod_a = module("a.ts");
+        let mod_b = module("b.ts");
 
         graph.add_module(&mod_a);
         graph.add_module(&mod_b);
 
-        let edge = DepEdge {
-            source: Some("a.ts".to_string()),
-            target: Some("b.ts".to_string()),
-            relation: DepKind::Import,
-            strength: 0.7,
-            files: None,
-        };
+        let edge = edge("b.ts");
 
         graph.add_edge("a.ts", "b.ts", edge);
 
@@ -55,18 +36,15 @@ mod tests {
     fn test_get_outgoing() {
         let mut graph = DependencyGraph::new();
 
-        let mod_a = create_test_module("a.ts");
-        let mod_b = create_test_module("b.ts");
+        let mod_a = module("a.ts");
+        let mod_b = module("b.ts");
 
         graph.add_module(&mod_a);
         graph.add_module(&mod_b);
 
         let edge = DepEdge {
-            source: Some("a.ts".to_string()),
-            target: Some("b.ts".to_string()),
-            relation: DepKind::Import,
             strength: 0.7,
-            files: None,
+            ..edge("b.ts")
         };
 
         graph.add_edge("a.ts", "b.ts", edge);
@@ -80,19 +58,13 @@ mod tests {
     fn test_get_incoming() {
         let mut graph = DependencyGraph::new();
 
-        let mod_a = create_test_module("a.ts");
-        let mod_b = create_test_module("b.ts");
+        let mod_a = module("a.ts");
+        let mod_b = module("b.ts");
 
         graph.add_module(&mod_a);
         graph.add_module(&mod_b);
 
-        let edge = DepEdge {
-            source: Some("a.ts".to_string()),
-            target: Some("b.ts".to_string()),
-            relation: DepKind::Import,
-            strength: 0.7,
-            files: None,
-        };
+        let edge = edge("b.ts");
 
         graph.add_edge("a.ts", "b.ts", edge);
 
@@ -104,28 +76,260 @@ mod tests {
     fn test_multiple_edges() {
         let mut graph = DependencyGraph::new();
 
-        graph.add_module(&create_test_module("a.ts"));
-        graph.add_module(&create_test_module("b.ts"));
-        graph.add_module(&create_test_module("c.ts"));
+        graph.add_module(&module("a.ts"));
+        graph.add_module(&module("b.ts"));
+        graph.add_module(&module("c.ts"));
 
-        graph.add_edge("a.ts", "b.ts", DepEdge {
-            source: Some("a.ts".to_string()),
-            target: Some("b.ts".to_string()),
-            relation: DepKind::Import,
-            strength: 0.7,
-            files: None,
-        });
+        graph.add_edge("a.ts", "b.ts", edge("b.ts"));
 
-        graph.add_edge("a.ts", "c.ts", DepEdge {
-            source: Some("a.ts".to_string()),
-            target: Some("c.ts".to_string()),
-            relation: DepKind::Import,
-            strength: 0.8,
-            files: None,
-        });
+        graph.add_edge("a.ts", "c.ts", edge("c.ts"));
 
         assert_eq!(graph.fan_out("a.ts"), 2);
         assert_eq!(graph.fan_in("b.ts"), 1);
         assert_eq!(graph.fan_in("c.ts"), 1);
     }
+
+    #[test]
+    fn test_fan_counts_distinct_modules() {
+        let mut graph = DependencyGraph::new();
+
+        graph.add_module(&module("a.ts"));
+        graph.add_module(&module("b.ts"));
+
+        // `import type` and a value import of the same module
+        graph.add_edge("a.ts", "b.ts", edge("b.ts"));
+        graph.add_edge("a.ts", "b.ts", edge("b.ts"));
+
+        assert_eq!(graph.fan_out("a.ts"), 1);
+        assert_eq!(graph.fan_in("b.ts"), 1);
+        assert_eq!(graph.get_incoming("b.ts").len(), 2);
+    }
+
+    #[test]
+    fn test_from_modules_links_resolved_imports() {
+        let mut app = module("./src/app.ts");
+        app.outgoing = vec![
+            edge("src/utils.ts"),
+            DepEdge {
+                resolution: Some(ResolutionKind::External),
+                ..edge("react")
+            },
+        ];
+        let mut view = module("./src/view.ts");
+        view.outgoing = vec![edge("src/utils.ts")];
+        let mut modules = vec![app, view, module("./src/utils.ts")];
+
+        let graph = DependencyGraph::from_modules(&mut modules);
+
+        assert_eq!(graph.module_count(), 3);
+        assert_eq!(graph.edge_count(), 2);
+        assert_eq!(graph.fan_in("./src/utils.ts"), 2);
+        assert_eq!(graph.fan_out("./src/app.ts"), 1);
+
+        // Edges carry the module paths as written, external targets are left alone
+        let app = &modules[0];
+        assert_eq!(app.outgoing[0].source.as_deref(), Some("./src/app.ts"));
+        assert_eq!(app.outgoing[0].target.as_deref(), Some("./src/utils.ts"));
+        assert_eq!(app.outgoing[1].target.as_deref(), Some("react"));
+
+        let sources: Vec<_> = modules[2]
+            .incoming
+            .iter()
+            .map(|e| e.source.as_deref().unwrap())
+            .collect();
+        assert_eq!(sources, vec!["./src/app.ts", "./src/view.ts"]);
+        assert!(modules[0].incoming.is_empty());
+    }
+
+    fn create_cyclic_graph(edges: &[(&str, &str)]) -> DependencyGraph {
+        let mut graph = DependencyGraph::new();
+        for (source, target) in edges {
+            graph.add_module(&module(source));
+            graph.add_module(&module(target));
+        }
+        for (source, target) in edges {
+            graph.add_edge(source, target, edge(target));
+        }
+        graph
+    }
+
+    #[test]
+    fn test_cycles_ordered_path() {
+        let graph = create_cyclic_graph(&[
+            ("b.ts", "c.ts"),
+            ("a.ts", "b.ts"),
+            ("c.ts", "a.ts"),
+            ("c.ts", "d.ts"),
+        ]);
+
+        let cycles = graph.cycles();
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(cycles[0].modules, vec!["a.ts", "b.ts", "c.ts"]);
+
+        let path: Vec<(&str, &str)> = cycles[0]
+            .path
+            .iter()
+            .map(|s| (s.source.as_str(), s.target.as_str()))
+            .collect();
+        assert_eq!(
+            path,
+            vec![("a.ts", "b.ts"), ("b.ts", "c.ts"), ("c.ts", "a.ts")]
+        );
+    }
+
+    #[test]
+    fn test_cycles_self_import_and_separate_components() {
+        let graph = create_cyclic_graph(&[
+            ("x.ts", "x.ts"),
+            ("a.ts", "b.ts"),
+            ("b.ts", "a.ts"),
+            ("b.ts", "c.ts"),
+        ]);
+
+        let cycles = graph.cycles();
+        let modules: Vec<Vec<String>> = cycles.iter().map(|c| c.modules.clone()).collect();
+        assert_eq!(
+            modules,
+            vec![
+                vec!["a.ts".to_string(), "b.ts".to_string()],
+                vec!["x.ts".to_string()]
+            ]
+        );
+        assert_eq!(cycles[1].path.len(), 1);
+    }
+
+    #[test]
+    fn test_cycles_ignore_type_only_imports() {
+        let mut graph = create_cyclic_graph(&[("a.ts", "b.ts")]);
+        let back = DepEdge {
+            type_only: true,
+            ..edge("a.ts")
+        };
+        graph.add_edge("b.ts", "a.ts", back);
+
+        assert!(graph.cycles().is_empty());
+    }
+
+    #[test]
+    fn test_dependents() {
+        let graph = create_cyclic_graph(&[
+            ("app.ts", "ui.ts"),
+            ("ui.ts", "util.ts"),
+            ("api.ts", "util.ts"),
+            ("app.ts", "api.ts"),
+            ("util.ts", "math.ts"),
+        ]);
+
+        // app.ts reaches util.ts through both ui.ts and api.ts: shortest distance wins
+        assert_eq!(
+            graph.dependents(&["util.ts"]),
+            vec![
+                ("api.ts".to_string(), 1),
+                ("ui.ts".to_string(), 1),
+                ("app.ts".to_string(), 2)
+            ]
+        );
+        assert_eq!(
+            graph.dependents(&["ui.ts", "math.ts"]),
+            vec![
+                ("app.ts".to_string(), 1),
+                ("util.ts".to_string(), 1),
+                ("api.ts".to_string(), 2)
+            ]
+        );
+        assert!(graph.dependents(&["missing.ts"]).is_empty());
+    }
+
+    #[test]
+    fn test_find_normalizes_paths() {
+        let graph = create_cyclic_graph(&[("./src/a.ts", "src/b.ts")]);
+        assert_eq!(
+            graph.find(std::path::Path::new("src/a.ts")),
+            Some("./src/a.ts")
+        );
+        assert_eq!(
+            graph.find(std::path::Path::new("./src/lib/../b.ts")),
+            Some("src/b.ts")
+        );
+        assert_eq!(graph.find(std::path::Path::new("src/c.ts")), None);
+    }
+
+    fn hops(path: &[crate::types::CycleStep]) -> Vec<&str> {
+        let mut modules: Vec<&str> = path.iter().map(|s| s.source.as_str()).collect();
+        modules.extend(path.last().map(|s| s.target.as_str()));
+        modules
+    }
+
+    #[test]
+    fn test_shortest_path() {
+        let mut graph = create_cyclic_graph(&[
+            ("a.ts", "b.ts"),
+            ("a.ts", "d.ts"),
+            ("b.ts", "c.ts"),
+            ("c.ts", "e.ts"),
+            ("e.ts", "a.ts"),
+        ]);
+        let type_import = DepEdge {
+            type_only: true,
+            ..edge("e.ts")
+        };
+        graph.add_edge("d.ts", "e.ts", type_import);
+
+        let path = graph.shortest_path("a.ts", "e.ts", false).unwrap();
+        assert_eq!(hops(&path), vec!["a.ts", "d.ts", "e.ts"]);
+        let path = graph.shortest_path("a.ts", "e.ts", true).unwrap();
+        assert_eq!(hops(&path), vec!["a.ts", "b.ts", "c.ts", "e.ts"]);
+        assert!(graph.shortest_path("d.ts", "b.ts", true).is_none());
+    }
+
+    #[test]
+    fn test_all_paths_bounded() {
+        let graph = create_cyclic_graph(&[
+            ("a.ts", "b.ts"),
+            ("a.ts", "d.ts"),
+            ("b.ts", "c.ts"),
+            ("c.ts", "e.ts"),
+            ("d.ts", "e.ts"),
+            ("e.ts", "a.ts"),
+        ]);
+
+        let paths = graph.all_paths("a.ts", "e.ts", 5, 10, false);
+        let chains: Vec<Vec<&str>> = paths.iter().map(|p| hops(p)).collect();
+        assert_eq!(
+            chains,
+            vec![
+                vec!["a.ts", "d.ts", "e.ts"],
+                vec!["a.ts", "b.ts", "c.ts", "e.ts"]
+            ]
+        );
+        assert_eq!(graph.all_paths("a.ts", "e.ts", 2, 10, false).len(), 1);
+        assert_eq!(graph.all_paths("a.ts", "e.ts", 5, 1, false).len(), 1);
+    }
+
+    #[test]
+    fn test_all_paths_limit_keeps_shortest() {
+        // `b.ts` is imported first, so a depth-first walk meets the long chain first
+        let graph = create_cyclic_graph(&[
+            ("a.ts", "b.ts"),
+            ("a.ts", "e.ts"),
+            ("b.ts", "c.ts"),
+            ("b.ts", "e.ts"),
+            ("c.ts", "d.ts"),
+            ("d.ts", "e.ts"),
+        ]);
+
+        let chains = |limit| -> Vec<Vec<String>> {
+            graph
+                .all_paths("a.ts", "e.ts", 5, limit, false)
+                .iter()
+                .map(|p| hops(p).into_iter().map(String::from).collect())
+                .collect()
+        };
+        assert_eq!(chains(1), vec![vec!["a.ts", "e.ts"]]);
+        assert_eq!(
+            chains(2),
+            vec![vec!["a.ts", "e.ts"], vec!["a.ts", "b.ts", "e.ts"]]
+        );
+        assert_eq!(chains(10).len(), 3);
+    }
 }
@@ -0,0 +1,453 @@
+use crate::graph::DependencyGraph;
+use crate::resolve::normalize;
+use crate::types::{ModuleIR, Result, Severity};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// What to draw from the dependency graph
+#[derive(Debug, Clone, Default)]
+pub struct GraphViewOptions {
+    /// Merge files into their parent directory
+    pub collapse: bool,
+    /// Keep only modules near this file
+    pub focus: Option<PathBuf>,
+    /// Import hops (in either direction) kept around `focus`, 1 when unset
+    pub depth: Option<usize>,
+    /// Prefix stripped from node labels, usually the analyzed directory
+    pub root: Option<PathBuf>,
+}
+
+/// A file, or a directory when collapsed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ViewNode {
+    pub id: String,
+    pub label: String,
+    pub files: u32,
+    pub loc: u32,
+    /// Highest cyclomatic complexity among the node's functions and methods
+    pub complexity: u32,
+    /// Distinct nodes importing this one, counted over the whole graph
+    pub fan_in: u32,
+    /// Distinct nodes this one imports, counted over the whole graph
+    pub fan_out: u32,
+    /// Worst severity among the module and symbol metrics
+    pub severity: Severity,
+}
+
+/// Imports from one node to another, merged when collapsed
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ViewEdge {
+    pub source: String,
+    pub target: String,
+    /// `DepEdge::relation_label` of the strongest import
+    pub relation: String,
+    pub strength: f32,
+    /// Number of imports merged into this edge
+    pub imports: u32,
+    pub type_only: bool,
+}
+
+/// A drawable projection of the `DependencyGraph`, shared by the graph writers
+#[derive(Debug, Clone, Default)]
+pub struct GraphView {
+    pub nodes: Vec<ViewNode>,
+    pub edges: Vec<ViewEdge>,
+}
+
+impl GraphView {
+    pub fn build(
+        graph: &DependencyGraph,
+        modules: &[ModuleIR],
+        options: &GraphViewOptions,
+    ) -> Result<Self> {
+        let by_path: HashMap<&str, &ModuleIR> =
+            modules.iter().map(|m| (m.path.as_str(), m)).collect();
+
+        let kept = match &options.focus {
+            Some(focus) => neighborhood(graph, focus, options.depth.unwrap_or(1))?,
+            None => graph.modules().collect(),
+        };
+
+        let group_of = |path: &str| -> String {
+            if options.collapse {
+                let parent = Path::new(path).parent().unwrap_or_else(|| Path::new(""));
+                parent.display().to_string()
+            } else {
+                path.to_string()
+            }
+        };
+
+        // Fan-in/out between nodes, including those filtered out by `focus`
+        let mut imported_by: HashMap<String, HashSet<String>> = HashMap::new();
+        let mut imports: HashMap<String, HashSet<String>> = HashMap::new();
+        for (source, target, _) in graph.edges() {
+            let (source, target) = (group_of(source), group_of(target));
+            if source != target || !options.collapse {
+                imported_by
+                    .entry(target.clone())
+                    .or_default()
+                    .insert(source.clone());
+                imports.entry(source).or_default().insert(target);
+            }
+        }
+        let count = |sets: &HashMap<String, HashSet<String>>, id: &str| {
+            sets.get(id).map_or(0, |set| set.len() as u32)
+        };
+
+        let mut nodes: BTreeMap<String, ViewNode> = BTreeMap::new();
+        for path in graph.modules().filter(|path| kept.contains(path)) {
+            let id = group_of(path);
+            let node = nodes.entry(id.clone()).or_insert_with(|| ViewNode {
+                label: label_for(&id, options.root.as_deref()),
+                files: 0,
+                loc: 0,
+                complexity: 0,
+                fan_in: count(&imported_by, &id),
+                fan_out: count(&imports, &id),
+                severity: Severity::Info,
+                id,
+            });
+            node.files += 1;
+            if let Some(module) = by_path.get(path) {
+                node.loc += module.loc;
+                node.complexity = node.complexity.max(max_complexity(module));
+                node.severity = node.severity.clone().max(worst_severity(module));
+            }
+        }
+
+        let mut edges: BTreeMap<(String, String), ViewEdge> = BTreeMap::new();
+        for (source, target, dep) in graph.edges() {
+            if !kept.contains(source) || !kept.contains(target) {
+                continue;
+            }
+            let (source, target) = (group_of(source), group_of(target));
+            if options.collapse && source == target {
+                continue;
+            }
+            let edge = edges
+                .entry((source.clone(), target.clone()))
+                .or_insert_with(|| ViewEdge {
+                    source,
+                    target,
+                    relation: dep.relation_label(),
+                    strength: dep.strength,
+                    imports: 0,
+                    type_only: true,
+                });
+            edge.imports += 1;
+            edge.type_only &= dep.type_only;
+            if dep.strength > edge.strength {
+                edge.strength = dep.strength;
+                edge.relation = dep.relation_label();
+            }
+        }
+
+        Ok(Self {
+            nodes: nodes.into_values().collect(),
+            edges: edges.into_values().collect(),
+        })
+    }
+
+    /// Graphviz DOT: nodes filled by severity, type-only edges dashed
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        dot.push_str("digraph codescope {\n");
+        dot.push_str("  rankdir=LR;\n");
+        dot.push_str("  node [shape=box, style=\"rounded,filled\", fontname=\"Helvetica\"];\n");
+        dot.push_str("  edge [fontname=\"Helvetica\", fontsize=10];\n");
+
+        for node in &self.nodes {
+            let _ = writeln!(
+                dot,
+                "  \"{}\" [label=\"{}\\n{}\", tooltip=\"{}\", fillcolor=\"{}\"];",
+                escape_dot(&node.id),
+                escape_dot(&node.label),
+                node_size(node),
+                node_metrics(node),
+                severity_color(&node.severity)
+            );
+        }
+
+        for edge in &self.edges {
+            let style = if edge.type_only { ", style=dashed" } else { "" };
+            let _ = writeln!(
+                dot,
+                "  \"{}\" -> \"{}\" [label=\"{}\", penwidth={:.1}{}];",
+                escape_dot(&edge.source),
+                escape_dot(&edge.target),
+                escape_dot(&edge_label(edge)),
+                1.0 + edge.strength * 2.0,
+                style
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Mermaid `flowchart` for Markdown docs; nodes get a class per severity
+    pub fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("flowchart LR\n");
+        let ids: HashMap<&str, String> = self
+            .nodes
+            .iter()
+            .enumerate()
+            .map(|(i, node)| (node.id.as_str(), format!("n{}", i)))
+            .collect();
+
+        for node in &self.nodes {
+            let _ = writeln!(
+                mermaid,
+                "  {}[\"{}<br/>{}\"]",
+                ids[node.id.as_str()],
+                escape_mermaid(&node.label),
+                node_size(node)
+            );
+        }
+
+        for edge in &self.edges {
+            let arrow = if edge.type_only { "-.->" } else { "-->" };
+            let _ = writeln!(
+                mermaid,
+                "  {} {}|\"{}\"| {}",
+                ids[edge.source.as_str()],
+                arrow,
+                escape_mermaid(&edge_label(edge)),
+                ids[edge.target.as_str()]
+            );
+        }
+
+        for severity in [Severity::Warning, Severity::Error] {
+            let members: Vec<&str> = self
+                .nodes
+                .iter()
+                .filter(|node| node.severity == severity)
+                .map(|node| ids[node.id.as_str()].as_str())
+                .collect();
+            if !members.is_empty() {
+                let class = severity_name(&severity);
+                let _ = writeln!(
+                    mermaid,
+                    "  classDef {} fill:{}",
+                    class,
+                    severity_color(&severity)
+                );
+                let _ = writeln!(mermaid, "  class {} {}", members.join(","), class);
+            }
+        }
+
+        mermaid
+    }
+
+    /// GraphML with node metrics and edge attributes as typed `<data>` keys (yEd, Gephi)
+    pub fn to_graphml(&self) -> String {
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        xml.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+        for (id, domain, kind) in [
+            ("label", "node", "string"),
+            ("files", "node", "int"),
+            ("loc", "node", "int"),
+            ("complexity", "node", "int"),
+            ("fan_in", "node", "int"),
+            ("fan_out", "node", "int"),
+            ("severity", "node", "string"),
+            ("relation", "edge", "string"),
+            ("strength", "edge", "double"),
+            ("imports", "edge", "int"),
+            ("type_only", "edge", "boolean"),
+        ] {
+            let _ = writeln!(
+                xml,
+                "  <key id=\"{0}\" for=\"{1}\" attr.name=\"{0}\" attr.type=\"{2}\"/>",
+                id, domain, kind
+            );
+        }
+        xml.push_str("  <graph id=\"codescope\" edgedefault=\"directed\">\n");
+
+        for node in &self.nodes {
+            let _ = writeln!(xml, "    <node id=\"{}\">", escape_xml(&node.id));
+            for (key, value) in [
+                ("label", escape_xml(&node.label)),
+                ("files", node.files.to_string()),
+                ("loc", node.loc.to_string()),
+                ("complexity", node.complexity.to_string()),
+                ("fan_in", node.fan_in.to_string()),
+                ("fan_out", node.fan_out.to_string()),
+                ("severity", severity_name(&node.severity).to_string()),
+            ] {
+                let _ = writeln!(xml, "      <data key=\"{}\">{}</data>", key, value);
+            }
+            xml.push_str("    </node>\n");
+        }
+
+        for (i, edge) in self.edges.iter().enumerate() {
+            let _ = writeln!(
+                xml,
+                "    <edge id=\"e{}\" source=\"{}\" target=\"{}\">",
+                i,
+                escape_xml(&edge.source),
+                escape_xml(&edge.target)
+            );
+            for (key, value) in [
+                ("relation", escape_xml(&edge.relation)),
+                ("strength", format!("{:.2}", edge.strength)),
+                ("imports", edge.imports.to_string()),
+                ("type_only", edge.type_only.to_string()),
+            ] {
+                let _ = writeln!(xml, "      <data key=\"{}\">{}</data>", key, value);
+            }
+            xml.push_str("    </edge>\n");
+        }
+
+        xml.push_str("  </graph>\n</graphml>\n");
+        xml
+    }
+
+    /// Cytoscape.js `elements` JSON, every node and edge attribute under `data`
+    pub fn to_cytoscape(&self) -> Result<String> {
+        let nodes: Vec<serde_json::Value> = self
+            .nodes
+            .iter()
+            .map(|node| serde_json::json!({ "data": node }))
+            .collect();
+        let edges: Vec<serde_json::Value> = self
+            .edges
+            .iter()
+            .enumerate()
+            .map(|(i, edge)| {
+                let mut data = serde_json::to_value(edge)?;
+                data["id"] = serde_json::Value::String(format!("e{}", i));
+                Ok(serde_json::json!({ "data": data }))
+            })
+            .collect::<Result<_>>()?;
+
+        let elements = serde_json::json!({ "elements": { "nodes": nodes, "edges": edges } });
+        Ok(serde_json::to_string_pretty(&elements)?)
+    }
+}
+
+/// `12 LOC` or `3 files, 40 LOC`
+fn node_size(node: &ViewNode) -> String {
+    if node.files > 1 {
+        format!("{} files, {} LOC", node.files, node.loc)
+    } else {
+        format!("{} LOC", node.loc)
+    }
+}
+
+fn node_metrics(node: &ViewNode) -> String {
+    format!(
+        "LOC {}, complexity {}, fan-in {}, fan-out {}",
+        node.loc, node.complexity, node.fan_in, node.fan_out
+    )
+}
+
+fn edge_label(edge: &ViewEdge) -> String {
+    if edge.imports > 1 {
+        format!("{} {:.2} ×{}", edge.relation, edge.strength, edge.imports)
+    } else {
+        format!("{} {:.2}", edge.relation, edge.strength)
+    }
+}
+
+/// Modules within `depth` imports of `focus`, following edges both ways
+fn neighborhood<'a>(
+    graph: &'a DependencyGraph,
+    focus: &Path,
+    depth: usize,
+) -> Result<HashSet<&'a str>> {
+    let focus = normalize(focus);
+    let start = graph
+        .find(&focus)
+        .ok_or_else(|| anyhow::anyhow!("Focus file is not in the graph: {}", focus.display()))?;
+
+    let mut adjacent: HashMap<&str, Vec<&str>> = HashMap::new();
+    for (source, target, _) in graph.edges() {
+        adjacent.entry(source).or_default().push(target);
+        adjacent.entry(target).or_default().push(source);
+    }
+
+    let mut kept = HashSet::from([start]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((path, distance)) = queue.pop_front() {
+        if distance == depth {
+            continue;
+        }
+        for &next in adjacent.get(path).into_iter().flatten() {
+            if kept.insert(next) {
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+    Ok(kept)
+}
+
+fn max_complexity(module: &ModuleIR) -> u32 {
+    module
+        .symbols
+        .iter()
+        .filter_map(|s| s.cyclomatic_complexity)
+        .max()
+        .unwrap_or(0)
+}
+
+fn worst_severity(module: &ModuleIR) -> Severity {
+    module
+        .metrics
+        .iter()
+        .chain(module.symbols.iter().flat_map(|s| s.metrics.iter()))
+        .map(|m| m.severity.clone())
+        .max()
+        .unwrap_or(Severity::Info)
+}
+
+fn label_for(id: &str, root: Option<&Path>) -> String {
+    let path = Path::new(id);
+    let relative = root
+        .and_then(|root| path.strip_prefix(root).ok())
+        .unwrap_or(path);
+    let label = relative.display().to_string();
+    if label.is_empty() {
+        ".".to_string()
+    } else {
+        label
+    }
+}
+
+fn severity_color(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "#f4a6a6",
+        Severity::Warning => "#ffe08a",
+        Severity::Info => "#d5f0d5",
+    }
+}
+
+fn escape_dot(text: &str) -> String {
+    text.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+fn escape_mermaid(text: &str) -> String {
+    text.replace('"', "#quot;")
+}
+
+fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn severity_name(severity: &Severity) -> &'static str {
+    match severity {
+        Severity::Error => "error",
+        Severity::Warning => "warning",
+        Severity::Info => "info",
+    }
+}
+
+#[cfg(test)]
+#[path = "graph_view_test.rs"]
+mod graph_view_test;
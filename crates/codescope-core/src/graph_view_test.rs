@@ -0,0 +1,206 @@
+#[cfg(test)]
+mod tests {
+    use crate::graph::DependencyGraph;
+    use crate::graph_view::{GraphView, GraphViewOptions};
+    use crate::test_fixtures::{metric, module_importing, symbol};
+    use crate::types::{ModuleIR, Severity, Symbol, SymbolKind};
+    use std::path::PathBuf;
+
+    /// `src/app.ts` imports two UI modules sharing `src/lib/dom.ts`; the form is oversized
+    fn create_test_modules() -> Vec<ModuleIR> {
+        let mut modules = vec![
+            module_importing("src/app.ts", &["src/ui/button.ts", "src/ui/form.ts"]),
+            module_importing("src/ui/button.ts", &["src/lib/dom.ts"]),
+            module_importing("src/ui/form.ts", &["src/ui/button.ts", "src/lib/dom.ts"]),
+            module_importing("src/lib/dom.ts", &[]),
+        ];
+        modules[2]
+            .metrics
+            .push(metric("file_loc", 500.0, Severity::Warning));
+        modules
+    }
+
+    fn build(modules: &mut [ModuleIR], options: GraphViewOptions) -> GraphView {
+        let graph = DependencyGraph::from_modules(modules);
+        GraphView::build(&graph, modules, &options).unwrap()
+    }
+
+    fn node_ids(view: &GraphView) -> Vec<&str> {
+        view.nodes.iter().map(|n| n.id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_build_file_view() {
+        let mut modules = create_test_modules();
+        let view = build(
+            &mut modules,
+            GraphViewOptions {
+                root: Some(PathBuf::from("src")),
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(view.nodes.len(), 4);
+        assert_eq!(view.edges.len(), 5);
+        let form = view
+            .nodes
+            .iter()
+            .find(|n| n.id == "src/ui/form.ts")
+            .unwrap();
+        assert_eq!(form.label, "ui/form.ts");
+        assert_eq!(form.severity, Severity::Warning);
+    }
+
+    #[test]
+    fn test_build_collapsed_view() {
+        let mut modules = create_test_modules();
+        let view = build(
+            &mut modules,
+            GraphViewOptions {
+                collapse: true,
+                ..Default::default()
+            },
+        );
+
+        assert_eq!(node_ids(&view), vec!["src", "src/lib", "src/ui"]);
+        let ui = &view.nodes[2];
+        assert_eq!((ui.files, ui.loc), (2, 20));
+        assert_eq!(ui.severity, Severity::Warning);
+
+        // button -> form inside src/ui is dropped, the two ui -> lib imports merge
+        let edges: Vec<(&str, &str, u32)> = view
+            .edges
+            .iter()
+            .map(|e| (e.source.as_str(), e.target.as_str(), e.imports))
+            .collect();
+        assert_eq!(edges, vec![("src", "src/ui", 2), ("src/ui", "src/lib", 2)]);
+    }
+
+    #[test]
+    fn test_build_focus_depth() {
+        let mut modules = create_test_modules();
+        let view = build(
+            &mut modules,
+            GraphViewOptions {
+                focus: Some(PathBuf::from("./src/lib/dom.ts")),
+                ..Default::default()
+            },
+        );
+        assert_eq!(
+            node_ids(&view),
+            vec!["src/lib/dom.ts", "src/ui/button.ts", "src/ui/form.ts"]
+        );
+
+        let view = build(
+            &mut modules,
+            GraphViewOptions {
+                focus: Some(PathBuf::from("src/lib/dom.ts")),
+                depth: Some(0),
+                ..Default::default()
+            },
+        );
+        assert_eq!(node_ids(&view), vec!["src/lib/dom.ts"]);
+        assert!(view.edges.is_empty());
+    }
+
+    #[test]
+    fn test_build_unknown_focus() {
+        let mut modules = create_test_modules();
+        let graph = DependencyGraph::from_modules(&mut modules);
+        let options = GraphViewOptions {
+            focus: Some(PathBuf::from("src/missing.ts")),
+            ..Default::default()
+        };
+        assert!(GraphView::build(&graph, &modules, &options).is_err());
+    }
+
+    #[test]
+    fn test_to_dot() {
+        let mut modules = create_test_modules();
+        let dot = build(&mut modules, GraphViewOptions::default()).to_dot();
+
+        assert!(dot.starts_with("digraph codescope {"));
+        assert!(dot.contains(
+            "\"src/ui/form.ts\" [label=\"src/ui/form.ts\\n10 LOC\", \
+             tooltip=\"LOC 10, complexity 0, fan-in 1, fan-out 2\", fillcolor=\"#ffe08a\"];"
+        ));
+        assert!(dot.contains("\"src/app.ts\" -> \"src/ui/button.ts\" [label=\"import 0.60\""));
+        assert!(dot.trim_end().ends_with('}'));
+    }
+
+    #[test]
+    fn test_node_metrics() {
+        let mut modules = create_test_modules();
+        modules[1].symbols.push(Symbol {
+            loc: 8,
+            cyclomatic_complexity: Some(7),
+            ..symbol("render", SymbolKind::Function)
+        });
+        let view = build(&mut modules, GraphViewOptions::default());
+
+        let button = view
+            .nodes
+            .iter()
+            .find(|n| n.id == "src/ui/button.ts")
+            .unwrap();
+        assert_eq!(
+            (button.complexity, button.fan_in, button.fan_out),
+            (7, 2, 1)
+        );
+
+        // Fan counts include modules outside the focus neighborhood
+        let view = build(
+            &mut modules,
+            GraphViewOptions {
+                focus: Some(PathBuf::from("src/lib/dom.ts")),
+                depth: Some(0),
+                ..Default::default()
+            },
+        );
+        assert_eq!(view.nodes[0].fan_in, 2);
+    }
+
+    #[test]
+    fn test_to_mermaid() {
+        let mut modules = create_test_modules();
+        let mermaid = build(&mut modules, GraphViewOptions::default()).to_mermaid();
+
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("  n0[\"src/app.ts<br/>10 LOC\"]\n"));
+        assert!(mermaid.contains("  n0 -->|\"import 0.60\"| n3\n"));
+        assert!(mermaid.contains("  classDef warning fill:#ffe08a\n  class n3 warning\n"));
+    }
+
+    #[test]
+    fn test_to_graphml() {
+        let mut modules = create_test_modules();
+        let graphml = build(&mut modules, GraphViewOptions::default()).to_graphml();
+
+        assert!(graphml
+            .contains("<key id=\"fan_in\" for=\"node\" attr.name=\"fan_in\" attr.type=\"int\"/>"));
+        assert!(graphml.contains("<node id=\"src/ui/form.ts\">"));
+        assert!(graphml.contains("<data key=\"severity\">warning</data>"));
+        assert!(
+            graphml.contains("<edge id=\"e0\" source=\"src/app.ts\" target=\"src/ui/button.ts\">")
+        );
+        assert!(graphml.trim_end().ends_with("</graphml>"));
+    }
+
+    #[test]
+    fn test_to_cytoscape() {
+        let mut modules = create_test_modules();
+        let json = build(&mut modules, GraphViewOptions::default())
+            .to_cytoscape()
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+
+        let nodes = value["elements"]["nodes"].as_array().unwrap();
+        let edges = value["elements"]["edges"].as_array().unwrap();
+        assert_eq!((nodes.len(), edges.len()), (4, 5));
+        assert_eq!(nodes[0]["data"]["id"], "src/app.ts");
+        assert_eq!(nodes[0]["data"]["fan_out"], 2);
+        assert_eq!(nodes[0]["data"]["severity"], "info");
+        assert_eq!(edges[0]["data"]["id"], "e0");
+        assert_eq!(edges[0]["data"]["source"], "src/app.ts");
+    }
+}
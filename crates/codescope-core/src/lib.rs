@@ -1,10 +1,30 @@
 pub mod types;
+pub mod aggregate;
+pub mod barrels;
+pub mod centrality;
+pub mod clusters;
+pub mod components;
+pub mod exports;
 pub mod graph;
+pub mod graph_view;
+pub mod reachability;
 pub mod metrics;
 pub mod discovery;
 pub mod cache;
 pub mod rules;
 pub mod config;
+pub mod resolve;
+pub mod tsconfig;
 
-pub use types::{Symbol, SymbolKind, ModuleIR, DepEdge, DepKind, QualityMetric, Severity};
+#[cfg(test)]
+mod test_fixtures;
+
+pub use types::{
+    AffectedModule, BindingKind, ChildEntry, Cluster, ClusterReport, ComponentMetrics, Cycle,
+    CycleStep, DepEdge, DepKind, DirectoryIR, DirectoryMetrics, DirectoryReport, Export,
+    ImpactReport, ImportBinding, ImportKind, MisplacedModule, ModuleIR, PathReport, QualityMetric,
+    RealEdge, ResolutionKind, Severity, Span, Symbol, SymbolKind, UnreachableModule,
+    UnreachableReport, Visibility,
+};
 pub use config::Config;
+pub use resolve::{ImportResolver, Resolution};
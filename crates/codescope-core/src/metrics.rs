@@ -1,4 +1,4 @@
-use crate::types::{ModuleIR, Symbol};
+use crate::types::{BindingKind, DepEdge, ImportKind, ModuleIR, Symbol};
 
 #[derive(Debug, Default)]
 pub struct LOCStats {
@@ -83,6 +83,29 @@ pub fn calculate_symbol_complexity(symbol: &Symbol) -> f64 {
     (1.0 - (1.0 / (1.0 + loc / 30.0))).clamp(0.0, 1.0)
 }
 
+/// Heuristic strength (0.0-1.0) of an import edge.
+/// Type-only imports vanish at runtime, dynamic imports are loaded lazily and
+/// side-effect imports bind nothing, so they couple modules less tightly.
+/// Otherwise the more of the target a module binds, the stronger the edge.
+pub fn import_strength(edge: &DepEdge) -> f32 {
+    if edge.type_only {
+        return 0.3;
+    }
+    match edge.import_kind {
+        Some(ImportKind::SideEffect) => 0.2,
+        Some(ImportKind::Dynamic) => 0.4,
+        _ if edge.bindings.is_empty() => 0.7,
+        _ if edge
+            .bindings
+            .iter()
+            .any(|b| b.kind == BindingKind::Namespace) =>
+        {
+            0.9
+        }
+        _ => (0.5 + 0.1 * edge.bindings.len() as f32).min(0.9),
+    }
+}
+
 pub fn generate_suggestions(module: &ModuleIR, fan_in: u32, fan_out: u32) -> Vec<String> {
     let mut suggestions = Vec::new();
 
@@ -1,7 +1,8 @@
 #[cfg(test)]
 mod tests {
     use crate::metrics::*;
-    use crate::types::{ModuleIR, Symbol, SymbolKind};
+    use crate::test_fixtures::{import_edge, module, symbol};
+    use crate::types::{DepEdge, ImportKind, ModuleIR, Symbol, SymbolKind, Visibility};
 
     #[test]
     fn test_count_lines_basic() {
@@ -34,15 +35,10 @@ fn test() {}
     #[test]
     fn test_generate_suggestions_large_file() {
         let module = ModuleIR {
-            path: "test.ts".to_string(),
-            language: Some("typescript".to_string()),
             loc: 600,
             comment_lines: 50,
             blank_lines: 30,
-            symbols: vec![],
-            metrics: vec![],
-            outgoing: vec![],
-            incoming: vec![],
+            ..module("test.ts")
         };
 
         let suggestions = generate_suggestions(&module, 2, 3);
@@ -52,15 +48,10 @@ fn test() {}
     #[test]
     fn test_generate_suggestions_high_fanout() {
         let module = ModuleIR {
-            path: "test.ts".to_string(),
-            language: Some("typescript".to_string()),
             loc: 100,
             comment_lines: 10,
             blank_lines: 5,
-            symbols: vec![],
-            metrics: vec![],
-            outgoing: vec![],
-            incoming: vec![],
+            ..module("test.ts")
         };
 
         let suggestions = generate_suggestions(&module, 2, 15);
@@ -70,21 +61,15 @@ fn test() {}
     #[test]
     fn test_generate_suggestions_large_symbol() {
         let module = ModuleIR {
-            path: "test.ts".to_string(),
-            language: Some("typescript".to_string()),
             loc: 200,
             comment_lines: 10,
             blank_lines: 5,
             symbols: vec![Symbol {
-                kind: SymbolKind::Function,
-                name: "largeFunction".to_string(),
                 loc: 100,
-                cyclomatic_complexity: None,
-                metrics: vec![],
+                visibility: Visibility::Internal,
+                ..symbol("largeFunction", SymbolKind::Function)
             }],
-            metrics: vec![],
-            outgoing: vec![],
-            incoming: vec![],
+            ..module("test.ts")
         };
 
         let suggestions = generate_suggestions(&module, 2, 3);
@@ -92,4 +77,38 @@ fn test() {}
             .iter()
             .any(|s| s.contains("largeFunction") && s.contains("LOC")));
     }
+
+    #[test]
+    fn test_import_strength_by_style() {
+        use ImportKind::{Dynamic, Require, SideEffect, Static};
+        let static_import = import_strength(&import_edge("./target", Static, &[]));
+        let require = import_strength(&import_edge("./target", Require, &[]));
+        let dynamic = import_strength(&import_edge("./target", Dynamic, &[]));
+        let type_only = import_strength(&DepEdge {
+            type_only: true,
+            ..import_edge("./target", Static, &[("A", "A")])
+        });
+        let side_effect = import_strength(&import_edge("./target", SideEffect, &[]));
+
+        assert_eq!(require, static_import);
+        assert!(dynamic < static_import);
+        assert!(type_only < static_import);
+        assert!(side_effect < type_only);
+    }
+
+    #[test]
+    fn test_import_strength_grows_with_bindings() {
+        use ImportKind::Static;
+        let one = import_strength(&import_edge("./target", Static, &[("a", "a")]));
+        let three = import_strength(&import_edge(
+            "./target",
+            Static,
+            &[("a", "a"), ("b", "b"), ("c", "c")],
+        ));
+        let namespace = import_strength(&import_edge("./target", Static, &[("*", "utils")]));
+
+        assert!(one < three);
+        assert!(three <= namespace);
+        assert!(namespace <= 1.0);
+    }
 }
@@ -0,0 +1,129 @@
+use crate::graph::DependencyGraph;
+use crate::resolve::{normalize, resolve_path};
+use anyhow::{Context, Result};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+/// Build output directories mapped back to `src` when a `package.json` entry names a
+/// compiled file, e.g. `./dist/index.js` -> `src/index.ts`
+const BUILD_DIRS: &[&str] = &["dist", "lib", "build", "out"];
+
+/// Files a program starts from: configured globs plus the `package.json` entries
+pub struct EntryPoints {
+    globs: GlobSet,
+}
+
+impl EntryPoints {
+    /// `patterns` are globs matched against paths relative to the project root
+    pub fn new(patterns: &[String]) -> Result<Self> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            let glob = GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+                .with_context(|| format!("Invalid entry point glob '{}'", pattern))?;
+            builder.add(glob);
+        }
+        Ok(Self {
+            globs: builder.build()?,
+        })
+    }
+
+    /// Graph modules matching a glob or declared by `root/package.json`, sorted
+    pub fn find<'a>(&self, graph: &'a DependencyGraph, root: &Path) -> Vec<&'a str> {
+        let root = normalize(root);
+        let mut entries: Vec<&str> = graph
+            .modules()
+            .filter(|module| {
+                let path = normalize(Path::new(module));
+                self.globs
+                    .is_match(path.strip_prefix(&root).unwrap_or(&path))
+            })
+            .collect();
+        entries.extend(
+            package_entry_points(&root)
+                .iter()
+                .filter_map(|path| graph.find(path)),
+        );
+        entries.sort_unstable();
+        entries.dedup();
+        entries
+    }
+}
+
+/// Source files behind `main`, `bin` and every `exports` target of `dir/package.json`
+pub fn package_entry_points(dir: &Path) -> Vec<PathBuf> {
+    let Ok(content) = fs::read_to_string(dir.join("package.json")) else {
+        return vec![];
+    };
+    let Ok(manifest) = serde_json::from_str::<serde_json::Value>(&content) else {
+        return vec![];
+    };
+
+    let mut targets = Vec::new();
+    for field in ["main", "bin", "exports"] {
+        if let Some(value) = manifest.get(field) {
+            collect_targets(value, &mut targets);
+        }
+    }
+
+    let mut paths: Vec<PathBuf> = targets
+        .iter()
+        .filter_map(|target| source_of(dir, target))
+        .collect();
+    paths.sort();
+    paths.dedup();
+    paths
+}
+
+/// Every string in a `bin` map or an `exports` map, whatever the subpath or condition
+fn collect_targets(value: &serde_json::Value, targets: &mut Vec<String>) {
+    match value {
+        serde_json::Value::String(target) => targets.push(target.clone()),
+        serde_json::Value::Object(entries) => {
+            for value in entries.values() {
+                collect_targets(value, targets);
+            }
+        }
+        serde_json::Value::Array(values) => {
+            for value in values {
+                collect_targets(value, targets);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// The file `target` names. Targets in a build directory resolve to their source first:
+/// build output is not analyzed, and may not exist until the project is built.
+/// Declaration files (`types` conditions) are not entry points.
+fn source_of(dir: &Path, target: &str) -> Option<PathBuf> {
+    if target.ends_with(".d.ts") {
+        return None;
+    }
+    let target = normalize(Path::new(target));
+    let mut components = target.components();
+    let source = match components.next() {
+        Some(Component::Normal(first)) if BUILD_DIRS.iter().any(|d| first == *d) => {
+            resolve_path(&dir.join("src").join(components.as_path()))
+        }
+        _ => None,
+    };
+    source.or_else(|| resolve_path(&dir.join(&target)))
+}
+
+/// Modules no entry point reaches through imports, sorted
+pub fn unreachable_modules<'a>(graph: &'a DependencyGraph, entries: &[&str]) -> Vec<&'a str> {
+    let reachable = graph.reachable(entries);
+    let mut unreachable: Vec<&str> = graph
+        .modules()
+        .filter(|module| !reachable.contains(module))
+        .collect();
+    unreachable.sort_unstable();
+    unreachable
+}
+
+#[cfg(test)]
+#[path = "reachability_test.rs"]
+mod reachability_test;
@@ -0,0 +1,105 @@
+#[cfg(test)]
+mod tests {
+    use crate::graph::DependencyGraph;
+    use crate::reachability::{package_entry_points, unreachable_modules, EntryPoints};
+    use crate::test_fixtures::{edge, module, module_importing, write};
+    use crate::types::{DepEdge, ModuleIR};
+
+    #[test]
+    fn test_unreachable_modules() {
+        let mut modules = vec![
+            module_importing("src/main.ts", &["src/app.ts"]),
+            ModuleIR {
+                outgoing: vec![DepEdge {
+                    type_only: true,
+                    ..edge("src/types.ts")
+                }],
+                ..module("src/app.ts")
+            },
+            module("src/types.ts"),
+            module_importing("src/old.ts", &["src/dead.ts", "src/app.ts"]),
+            module_importing("src/dead.ts", &["src/old.ts"]),
+        ];
+        let graph = DependencyGraph::from_modules(&mut modules);
+
+        // A type-only import still keeps its target alive; a dead cycle stays dead
+        assert_eq!(
+            unreachable_modules(&graph, &["src/main.ts"]),
+            vec!["src/dead.ts", "src/old.ts"]
+        );
+        assert_eq!(
+            unreachable_modules(&graph, &["src/old.ts"]),
+            vec!["src/main.ts"]
+        );
+    }
+
+    #[test]
+    fn test_package_entry_points() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "package.json",
+            r#"{
+                "main": "./dist/index.js",
+                "bin": { "tool": "./bin/tool.js" },
+                "exports": {
+                    ".": { "types": "./dist/index.d.ts", "import": "./dist/index.js" },
+                    "./extra": "./lib/extra.js"
+                }
+            }"#,
+        );
+        write(root, "src/index.ts", "");
+        write(root, "src/extra.ts", "");
+        write(root, "bin/tool.js", "");
+
+        assert_eq!(
+            package_entry_points(root),
+            vec![
+                root.join("bin/tool.js"),
+                root.join("src/extra.ts"),
+                root.join("src/index.ts"),
+            ]
+        );
+        assert!(package_entry_points(&root.join("src")).is_empty());
+
+        // Once built, `dist` holds the same entries but they still map to `src`
+        write(root, "dist/index.js", "");
+        write(root, "dist/index.d.ts", "");
+        write(root, "lib/extra.js", "");
+        write(root, "lib/only-built.js", "");
+        write(
+            root,
+            "package.json",
+            r#"{ "main": "./dist/index.js", "bin": "./lib/only-built.js" }"#,
+        );
+        assert_eq!(
+            package_entry_points(root),
+            vec![root.join("lib/only-built.js"), root.join("src/index.ts")]
+        );
+    }
+
+    #[test]
+    fn test_find_entry_points() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "package.json", r#"{ "main": "src/index.ts" }"#);
+        write(root, "src/index.ts", "");
+
+        let path = |p: &str| root.join(p).display().to_string();
+        let mut modules = vec![
+            module(&path("src/index.ts")),
+            module(&path("scripts/seed.ts")),
+            module(&path("scripts/lib/db.ts")),
+            module(&path("src/util.ts")),
+        ];
+        let graph = DependencyGraph::from_modules(&mut modules);
+
+        let entries = EntryPoints::new(&["scripts/*.ts".to_string()]).unwrap();
+        assert_eq!(
+            entries.find(&graph, root),
+            vec![path("scripts/seed.ts"), path("src/index.ts")]
+        );
+        assert!(EntryPoints::new(&["src/[".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,247 @@
+use crate::tsconfig::TsConfig;
+use crate::types::{ModuleIR, ResolutionKind};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+/// Extensions tried, in order, for specifiers written without one. Declaration files come
+/// last: they are not parsed, so a `.js` file with a `.d.ts` sidecar must win.
+const SOURCE_EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx", "mts", "cts", "mjs", "cjs", "d.ts"];
+
+/// Conditions picked from a `package.json` `exports` map, in order of preference
+const EXPORT_CONDITIONS: &[&str] = &["import", "module", "default", "require", "types"];
+
+/// Where an import specifier points
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Resolution {
+    /// A file in the repository
+    Internal(PathBuf),
+    /// A package from `node_modules` (or a Node builtin), by package name
+    External(String),
+    /// A relative specifier with no matching file
+    Unresolved,
+}
+
+impl Resolution {
+    pub fn kind(&self) -> ResolutionKind {
+        match self {
+            Resolution::Internal(_) => ResolutionKind::Internal,
+            Resolution::External(_) => ResolutionKind::External,
+            Resolution::Unresolved => ResolutionKind::Unresolved,
+        }
+    }
+}
+
+/// Maps import specifiers to files the way Node and TypeScript's bundler resolution do,
+/// including `paths`/`baseUrl` aliases from the nearest `tsconfig.json` or `jsconfig.json`
+#[derive(Debug, Default)]
+pub struct ImportResolver {
+    tsconfigs: Mutex<TsConfigCache>,
+}
+
+#[derive(Debug, Default)]
+struct TsConfigCache {
+    /// Source directory -> nearest config file
+    nearest: HashMap<PathBuf, Option<PathBuf>>,
+    /// Config file -> loaded config, `None` when it could not be read
+    loaded: HashMap<PathBuf, Option<Arc<TsConfig>>>,
+}
+
+impl ImportResolver {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves `specifier` as imported from the file `importer`
+    pub fn resolve(&self, importer: &Path, specifier: &str) -> Resolution {
+        let importer = normalize(importer);
+        let dir = importer.parent().unwrap_or_else(|| Path::new(""));
+
+        if !is_relative(specifier) {
+            let aliased = self.tsconfig_for(dir).and_then(|config| {
+                config
+                    .candidates(specifier)
+                    .iter()
+                    .find_map(|candidate| resolve_path(candidate))
+            });
+            return match aliased {
+                Some(path) => Resolution::Internal(path),
+                None => Resolution::External(package_name(specifier)),
+            };
+        }
+
+        let base = normalize(&dir.join(specifier));
+        match resolve_path(&base) {
+            Some(path) => Resolution::Internal(path),
+            None => Resolution::Unresolved,
+        }
+    }
+
+    fn tsconfig_for(&self, dir: &Path) -> Option<Arc<TsConfig>> {
+        let mut cache = self.tsconfigs.lock().unwrap_or_else(|e| e.into_inner());
+        let config_path = cache
+            .nearest
+            .entry(dir.to_path_buf())
+            .or_insert_with(|| TsConfig::find(dir))
+            .clone()?;
+        cache
+            .loaded
+            .entry(config_path)
+            .or_insert_with_key(|path| TsConfig::load(path).ok().map(Arc::new))
+            .clone()
+    }
+
+    /// Rewrites the targets of `module`'s outgoing edges to resolved paths or package names
+    pub fn resolve_module(&self, module: &mut ModuleIR) {
+        let importer = PathBuf::from(&module.path);
+        for edge in &mut module.outgoing {
+            let Some(specifier) = edge.specifier.clone().or_else(|| edge.target.clone()) else {
+                continue;
+            };
+            let resolution = self.resolve(&importer, &specifier);
+            edge.resolution = Some(resolution.kind());
+            match resolution {
+                Resolution::Internal(path) => edge.target = Some(path.display().to_string()),
+                Resolution::External(package) => edge.target = Some(package),
+                Resolution::Unresolved => edge.target = Some(specifier),
+            }
+        }
+    }
+}
+
+fn is_relative(specifier: &str) -> bool {
+    specifier == "."
+        || specifier == ".."
+        || specifier.starts_with("./")
+        || specifier.starts_with("../")
+        || specifier.starts_with('/')
+}
+
+/// `lodash/fp` -> `lodash`, `@scope/pkg/sub` -> `@scope/pkg`
+fn package_name(specifier: &str) -> String {
+    let segments = if specifier.starts_with('@') { 2 } else { 1 };
+    specifier
+        .splitn(segments + 1, '/')
+        .take(segments)
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Resolves a path without touching the filesystem for `.` and `..`, so symlinks are kept
+pub fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                let popped = matches!(
+                    normalized.components().next_back(),
+                    Some(Component::Normal(_))
+                ) && normalized.pop();
+                if !popped && !normalized.has_root() {
+                    normalized.push("..");
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+    normalized
+}
+
+pub(crate) fn resolve_path(base: &Path) -> Option<PathBuf> {
+    resolve_file(base).or_else(|| resolve_directory(base))
+}
+
+fn resolve_file(base: &Path) -> Option<PathBuf> {
+    // ESM-style TypeScript imports name the compiled file: `./util.js` -> `./util.ts`
+    if let Some(ext) = base.extension().and_then(|ext| ext.to_str()) {
+        let sources: &[&str] = match ext {
+            "js" => &["ts", "tsx"],
+            "jsx" => &["tsx"],
+            "mjs" => &["mts"],
+            "cjs" => &["cts"],
+            _ => &[],
+        };
+        if let Some(path) = sources
+            .iter()
+            .map(|source| base.with_extension(source))
+            .find(|path| path.is_file())
+        {
+            return Some(path);
+        }
+    }
+
+    if base.is_file() {
+        return Some(base.to_path_buf());
+    }
+
+    SOURCE_EXTENSIONS
+        .iter()
+        .map(|ext| with_added_extension(base, ext))
+        .find(|path| path.is_file())
+}
+
+fn resolve_directory(dir: &Path) -> Option<PathBuf> {
+    if !dir.is_dir() {
+        return None;
+    }
+
+    if let Some(entry) = package_entry(dir) {
+        let entry = normalize(&dir.join(entry));
+        if let Some(path) = resolve_file(&entry).or_else(|| resolve_index(&entry)) {
+            return Some(path);
+        }
+    }
+
+    resolve_index(dir)
+}
+
+fn resolve_index(dir: &Path) -> Option<PathBuf> {
+    SOURCE_EXTENSIONS
+        .iter()
+        .map(|ext| dir.join(format!("index.{}", ext)))
+        .find(|path| path.is_file())
+}
+
+/// Entry point declared by `dir/package.json`: `exports["."]`, `exports`, then `main`
+fn package_entry(dir: &Path) -> Option<String> {
+    let content = fs::read_to_string(dir.join("package.json")).ok()?;
+    let manifest: serde_json::Value = serde_json::from_str(&content).ok()?;
+
+    if let Some(exports) = manifest.get("exports") {
+        let root = exports.get(".").unwrap_or(exports);
+        if let Some(entry) = export_target(root) {
+            return Some(entry);
+        }
+    }
+
+    manifest
+        .get("main")
+        .and_then(|main| main.as_str())
+        .map(String::from)
+}
+
+fn export_target(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::String(target) => Some(target.clone()),
+        serde_json::Value::Object(conditions) => EXPORT_CONDITIONS
+            .iter()
+            .filter_map(|condition| conditions.get(*condition))
+            .find_map(export_target),
+        serde_json::Value::Array(targets) => targets.iter().find_map(export_target),
+        _ => None,
+    }
+}
+
+/// `utils` + `d.ts` -> `utils.d.ts`; unlike `with_extension`, keeps dots already in the name
+fn with_added_extension(base: &Path, ext: &str) -> PathBuf {
+    let mut path = base.as_os_str().to_owned();
+    path.push(".");
+    path.push(ext);
+    PathBuf::from(path)
+}
+
+#[cfg(test)]
+#[path = "resolve_test.rs"]
+mod resolve_test;
@@ -0,0 +1,218 @@
+#[cfg(test)]
+mod tests {
+    use crate::resolve::{normalize, ImportResolver, Resolution};
+    use crate::test_fixtures::{self, write};
+    use crate::types::{DepEdge, ModuleIR, ResolutionKind};
+    use std::path::{Path, PathBuf};
+
+    fn resolve(root: &Path, importer: &str, specifier: &str) -> Resolution {
+        ImportResolver::new().resolve(&root.join(importer), specifier)
+    }
+
+    fn internal(root: &Path, path: &str) -> Resolution {
+        Resolution::Internal(root.join(path))
+    }
+
+    #[test]
+    fn test_resolve_extensions() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "src/utils.ts", "");
+        write(root, "src/view.tsx", "");
+        write(root, "src/legacy.js", "");
+        write(root, "src/types.d.ts", "");
+        write(root, "src/lib.js", "");
+        write(root, "src/lib.d.ts", "");
+        write(root, "src/styles.css", "");
+        write(root, "src/app.ts", "");
+
+        assert_eq!(
+            resolve(root, "src/app.ts", "./utils"),
+            internal(root, "src/utils.ts")
+        );
+        assert_eq!(
+            resolve(root, "src/app.ts", "./view"),
+            internal(root, "src/view.tsx")
+        );
+        assert_eq!(
+            resolve(root, "src/app.ts", "./legacy"),
+            internal(root, "src/legacy.js")
+        );
+        assert_eq!(
+            resolve(root, "src/app.ts", "./types"),
+            internal(root, "src/types.d.ts")
+        );
+        // A typing sidecar does not hide the JavaScript file it describes
+        assert_eq!(
+            resolve(root, "src/app.ts", "./lib"),
+            internal(root, "src/lib.js")
+        );
+        assert_eq!(
+            resolve(root, "src/app.ts", "./styles.css"),
+            internal(root, "src/styles.css")
+        );
+        // ESM-style imports of the compiled file resolve to the TypeScript source
+        assert_eq!(
+            resolve(root, "src/app.ts", "./utils.js"),
+            internal(root, "src/utils.ts")
+        );
+        assert_eq!(
+            resolve(root, "src/app.ts", "./missing"),
+            Resolution::Unresolved
+        );
+    }
+
+    #[test]
+    fn test_resolve_parent_and_index() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "src/utils/index.ts", "");
+        write(root, "src/features/cart/cart.ts", "");
+
+        assert_eq!(
+            resolve(root, "src/features/cart/cart.ts", "../../utils"),
+            internal(root, "src/utils/index.ts")
+        );
+        assert_eq!(
+            resolve(root, "src/features/cart/cart.ts", "../../utils/"),
+            internal(root, "src/utils/index.ts")
+        );
+    }
+
+    #[test]
+    fn test_resolve_package_json() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "packages/core/package.json",
+            r#"{ "main": "lib/main.js" }"#,
+        );
+        write(root, "packages/core/lib/main.ts", "");
+        write(
+            root,
+            "packages/ui/package.json",
+            r#"{ "main": "ignored.js", "exports": { ".": { "types": "./types.d.ts", "import": "./src/index.ts" } } }"#,
+        );
+        write(root, "packages/ui/src/index.ts", "");
+        write(root, "app.ts", "");
+
+        assert_eq!(
+            resolve(root, "app.ts", "./packages/core"),
+            internal(root, "packages/core/lib/main.ts")
+        );
+        assert_eq!(
+            resolve(root, "app.ts", "./packages/ui"),
+            internal(root, "packages/ui/src/index.ts")
+        );
+    }
+
+    #[test]
+    fn test_resolve_external_packages() {
+        let resolver = ImportResolver::new();
+        let importer = Path::new("src/app.ts");
+
+        assert_eq!(
+            resolver.resolve(importer, "react"),
+            Resolution::External("react".to_string())
+        );
+        assert_eq!(
+            resolver.resolve(importer, "lodash/fp"),
+            Resolution::External("lodash".to_string())
+        );
+        assert_eq!(
+            resolver.resolve(importer, "@scope/pkg/sub/path"),
+            Resolution::External("@scope/pkg".to_string())
+        );
+        assert_eq!(
+            resolver.resolve(importer, "node:fs"),
+            Resolution::External("node:fs".to_string())
+        );
+    }
+
+    #[test]
+    fn test_resolve_module_rewrites_targets() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "src/utils.ts", "");
+
+        let edge = |specifier: &str| DepEdge {
+            strength: 0.7,
+            specifier: Some(specifier.to_string()),
+            ..test_fixtures::edge(specifier)
+        };
+        let mut module = ModuleIR {
+            loc: 1,
+            outgoing: vec![edge("./utils"), edge("react/jsx-runtime"), edge("./gone")],
+            ..test_fixtures::module(&root.join("src/app.ts").display().to_string())
+        };
+
+        ImportResolver::new().resolve_module(&mut module);
+
+        let targets: Vec<(Option<&str>, Option<ResolutionKind>)> = module
+            .outgoing
+            .iter()
+            .map(|e| (e.target.as_deref(), e.resolution))
+            .collect();
+        let utils = root.join("src/utils.ts").display().to_string();
+        assert_eq!(
+            targets,
+            vec![
+                (Some(utils.as_str()), Some(ResolutionKind::Internal)),
+                (Some("react"), Some(ResolutionKind::External)),
+                (Some("./gone"), Some(ResolutionKind::Unresolved)),
+            ]
+        );
+        assert_eq!(module.outgoing[0].specifier.as_deref(), Some("./utils"));
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(
+            normalize(Path::new("./src/./a/../b.ts")),
+            PathBuf::from("src/b.ts")
+        );
+        assert_eq!(
+            normalize(Path::new("src/../../lib")),
+            PathBuf::from("../lib")
+        );
+        assert_eq!(normalize(Path::new("/a/../../b")), PathBuf::from("/b"));
+    }
+
+    #[test]
+    fn test_resolve_tsconfig_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "tsconfig.base.json",
+            r#"{ "compilerOptions": { "baseUrl": ".", "paths": { "@app/core/*": ["packages/core/src/*"] } } }"#,
+        );
+        write(
+            root,
+            "apps/web/tsconfig.json",
+            r#"{ "extends": "../../tsconfig.base.json" }"#,
+        );
+        write(root, "packages/core/src/math/index.ts", "");
+        write(root, "shared/log.ts", "");
+        write(root, "apps/web/main.ts", "");
+
+        assert_eq!(
+            resolve(root, "apps/web/main.ts", "@app/core/math"),
+            internal(root, "packages/core/src/math/index.ts")
+        );
+        // `baseUrl` makes non-relative imports resolve from the repository root
+        assert_eq!(
+            resolve(root, "apps/web/main.ts", "shared/log"),
+            internal(root, "shared/log.ts")
+        );
+        assert_eq!(
+            resolve(root, "apps/web/main.ts", "@app/other"),
+            Resolution::External("@app/other".to_string())
+        );
+        assert_eq!(
+            resolve(root, "apps/web/main.ts", "react"),
+            Resolution::External("react".to_string())
+        );
+    }
+}
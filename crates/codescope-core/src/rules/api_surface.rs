@@ -0,0 +1,82 @@
+use crate::rules::QualityRule;
+use crate::types::{ImportKind, ModuleIR, QualityMetric, Severity, Symbol};
+
+/// Size of a module's public API: what it exports and how much code sits behind it
+pub struct ApiSurfaceRule {
+    pub max_exports: usize,
+    pub severity: Severity,
+}
+
+impl ApiSurfaceRule {
+    pub fn new(max_exports: usize, severity: Severity) -> Self {
+        Self {
+            max_exports,
+            severity,
+        }
+    }
+}
+
+impl Default for ApiSurfaceRule {
+    fn default() -> Self {
+        Self::new(20, Severity::Warning)
+    }
+}
+
+impl QualityRule for ApiSurfaceRule {
+    fn name(&self) -> &str {
+        "api_surface"
+    }
+
+    fn check_module(&self, module: &ModuleIR) -> Vec<QualityMetric> {
+        let mut metrics = vec![];
+
+        // Own exports plus names forwarded with `export ... from`. The object of
+        // `module.exports = { a, b }` is not counted on top of its properties.
+        let own_count = module.exports.iter().filter(|e| !e.aggregate).count();
+        let reexport_count: usize = module
+            .outgoing
+            .iter()
+            .filter(|dep| dep.import_kind == Some(ImportKind::ReExport))
+            .map(|dep| dep.bindings.len().max(1))
+            .sum();
+        let export_count = own_count + reexport_count;
+        metrics.push(QualityMetric {
+            name: "export_count".to_string(),
+            value: export_count as f64,
+            threshold: Some(self.max_exports as f64),
+            severity: if export_count > self.max_exports {
+                self.severity.clone()
+            } else {
+                Severity::Info
+            },
+            message: if export_count > self.max_exports {
+                Some(format!(
+                    "Module exports {} names, exceeds threshold of {}",
+                    export_count, self.max_exports
+                ))
+            } else {
+                None
+            },
+        });
+
+        let exported_loc: u32 = module
+            .symbols
+            .iter()
+            .filter(|s| s.visibility.is_exported())
+            .map(|s| s.loc)
+            .sum();
+        metrics.push(QualityMetric {
+            name: "exported_loc".to_string(),
+            value: exported_loc as f64,
+            threshold: None,
+            severity: Severity::Info,
+            message: None,
+        });
+
+        metrics
+    }
+
+    fn check_symbol(&self, _symbol: &Symbol) -> Vec<QualityMetric> {
+        vec![]
+    }
+}
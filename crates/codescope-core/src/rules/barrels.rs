@@ -0,0 +1,60 @@
+use crate::barrels::{is_barrel, reexport_count};
+use crate::rules::QualityRule;
+use crate::types::{ModuleIR, QualityMetric, Severity, Symbol};
+
+/// Size of barrel modules: every `export ... from` in a barrel is a dependency its
+/// importers take on, and code bundlers may not be able to drop
+pub struct BarrelRule {
+    pub max_reexports: usize,
+    pub severity: Severity,
+}
+
+impl BarrelRule {
+    pub fn new(max_reexports: usize, severity: Severity) -> Self {
+        Self {
+            max_reexports,
+            severity,
+        }
+    }
+}
+
+impl Default for BarrelRule {
+    fn default() -> Self {
+        Self::new(10, Severity::Warning)
+    }
+}
+
+impl QualityRule for BarrelRule {
+    fn name(&self) -> &str {
+        "barrels"
+    }
+
+    fn check_module(&self, module: &ModuleIR) -> Vec<QualityMetric> {
+        if !is_barrel(module) {
+            return vec![];
+        }
+
+        let reexports = reexport_count(module);
+        let exceeded = reexports > self.max_reexports;
+        vec![QualityMetric {
+            name: "barrel_reexports".to_string(),
+            value: reexports as f64,
+            threshold: Some(self.max_reexports as f64),
+            severity: if exceeded {
+                self.severity.clone()
+            } else {
+                Severity::Info
+            },
+            message: exceeded.then(|| {
+                format!(
+                    "Barrel has {} re-exports, exceeds threshold of {}",
+                    reexports, self.max_reexports
+                )
+            }),
+        }]
+    }
+
+    fn check_symbol(&self, _symbol: &Symbol) -> Vec<QualityMetric> {
+        vec![]
+    }
+}
@@ -0,0 +1,103 @@
+use crate::exports::ExportIndex;
+use crate::graph::DependencyGraph;
+use crate::rules::{GraphMetric, QualityRule};
+use crate::types::{
+    BindingKind, DepEdge, ImportKind, ModuleIR, QualityMetric, ResolutionKind, Severity, Symbol,
+};
+
+/// Flags relative imports that resolve to no file, and named or default imports that the
+/// resolved file does not export
+pub struct BrokenImportsRule {
+    pub severity: Severity,
+}
+
+impl BrokenImportsRule {
+    pub fn new(severity: Severity) -> Self {
+        Self { severity }
+    }
+
+    fn metric(&self, module: &ModuleIR, edge: &DepEdge, problem: String) -> QualityMetric {
+        let line = edge
+            .span
+            .map(|span| format!(":{}", span.start_line))
+            .unwrap_or_default();
+        QualityMetric {
+            name: "broken_import".to_string(),
+            value: 1.0,
+            threshold: None,
+            severity: self.severity.clone(),
+            message: Some(format!(
+                "Broken import: {} ({}{})",
+                problem, module.path, line
+            )),
+        }
+    }
+}
+
+impl Default for BrokenImportsRule {
+    fn default() -> Self {
+        Self::new(Severity::Error)
+    }
+}
+
+impl QualityRule for BrokenImportsRule {
+    fn name(&self) -> &str {
+        "broken_imports"
+    }
+
+    fn check_module(&self, module: &ModuleIR) -> Vec<QualityMetric> {
+        module
+            .outgoing
+            .iter()
+            .filter(|edge| edge.resolution == Some(ResolutionKind::Unresolved))
+            .map(|edge| {
+                let specifier = edge
+                    .specifier
+                    .as_deref()
+                    .or(edge.target.as_deref())
+                    .unwrap_or_default();
+                self.metric(module, edge, format!("'{}' resolves to no file", specifier))
+            })
+            .collect()
+    }
+
+    fn check_symbol(&self, _symbol: &Symbol) -> Vec<QualityMetric> {
+        vec![]
+    }
+
+    fn check_modules(&self, _graph: &DependencyGraph, modules: &[ModuleIR]) -> Vec<GraphMetric> {
+        let index = ExportIndex::new(modules);
+        let mut metrics = vec![];
+
+        for module in modules {
+            for edge in &module.outgoing {
+                if !matches!(
+                    edge.import_kind,
+                    Some(ImportKind::Static) | Some(ImportKind::ReExport)
+                ) || edge.resolution != Some(ResolutionKind::Internal)
+                {
+                    continue;
+                }
+                let Some(target) = edge.target.as_deref() else {
+                    continue;
+                };
+
+                for binding in &edge.bindings {
+                    if binding.kind == BindingKind::Namespace
+                        || index.exports_name(target, &binding.imported)
+                    {
+                        continue;
+                    }
+                    let problem = format!("{} does not export '{}'", target, binding.imported);
+                    metrics.push(GraphMetric {
+                        path: module.path.clone(),
+                        symbol: None,
+                        metric: self.metric(module, edge, problem),
+                    });
+                }
+            }
+        }
+
+        metrics
+    }
+}
@@ -0,0 +1,58 @@
+use crate::centrality::{betweenness, pagerank};
+use crate::graph::DependencyGraph;
+use crate::rules::{GraphMetric, QualityRule};
+use crate::types::{ModuleIR, QualityMetric, Severity, Symbol};
+
+/// Reports PageRank and betweenness centrality for every module
+#[derive(Default)]
+pub struct CentralityRule;
+
+impl CentralityRule {
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl QualityRule for CentralityRule {
+    fn name(&self) -> &str {
+        "centrality"
+    }
+
+    fn check_module(&self, _module: &ModuleIR) -> Vec<QualityMetric> {
+        vec![]
+    }
+
+    fn check_symbol(&self, _symbol: &Symbol) -> Vec<QualityMetric> {
+        vec![]
+    }
+
+    fn check_graph(&self, graph: &DependencyGraph) -> Vec<GraphMetric> {
+        let ranks = pagerank(graph);
+        let betweenness = betweenness(graph);
+
+        let metric = |name: &str, value: f64| QualityMetric {
+            name: name.to_string(),
+            value,
+            threshold: None,
+            severity: Severity::Info,
+            message: None,
+        };
+        graph
+            .modules()
+            .flat_map(|path| {
+                [
+                    GraphMetric {
+                        path: path.to_string(),
+                        symbol: None,
+                        metric: metric("pagerank", ranks[path]),
+                    },
+                    GraphMetric {
+                        path: path.to_string(),
+                        symbol: None,
+                        metric: metric("betweenness", betweenness[path]),
+                    },
+                ]
+            })
+            .collect()
+    }
+}
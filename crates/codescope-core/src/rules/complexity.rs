@@ -1,5 +1,5 @@
 use crate::rules::QualityRule;
-use crate::types::{ModuleIR, QualityMetric, Severity, Symbol, SymbolKind};
+use crate::types::{ModuleIR, QualityMetric, Severity, Symbol};
 
 pub struct ComplexityRule {
     pub max_complexity: u32,
@@ -31,8 +31,7 @@ impl QualityRule for ComplexityRule {
             .symbols
             .iter()
             .filter(|s| {
-                s.kind == SymbolKind::Function
-                    && s.cyclomatic_complexity.unwrap_or(0) > self.max_complexity
+                s.kind.is_callable() && s.cyclomatic_complexity.unwrap_or(0) > self.max_complexity
             })
             .collect();
 
@@ -68,7 +67,7 @@ impl QualityRule for ComplexityRule {
     }
 
     fn check_symbol(&self, symbol: &Symbol) -> Vec<QualityMetric> {
-        if symbol.kind == SymbolKind::Function {
+        if symbol.kind.is_callable() {
             if let Some(complexity) = symbol.cyclomatic_complexity {
                 if complexity > self.max_complexity {
                     return vec![QualityMetric {
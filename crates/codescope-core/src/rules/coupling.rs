@@ -1,5 +1,6 @@
 use crate::rules::QualityRule;
 use crate::types::{ModuleIR, QualityMetric, Severity, Symbol};
+use std::collections::HashSet;
 
 pub struct CouplingRule {
     pub max_fan_out: usize,
@@ -31,7 +32,13 @@ impl QualityRule for CouplingRule {
     fn check_module(&self, module: &ModuleIR) -> Vec<QualityMetric> {
         let mut metrics = vec![];
 
-        let fan_out = module.outgoing.len();
+        // Distinct modules and packages, not import statements
+        let fan_out = module
+            .outgoing
+            .iter()
+            .filter_map(|dep| dep.target.as_deref())
+            .collect::<HashSet<_>>()
+            .len();
         metrics.push(QualityMetric {
             name: "fan_out".to_string(),
             value: fan_out as f64,
@@ -51,7 +58,12 @@ impl QualityRule for CouplingRule {
             },
         });
 
-        let fan_in = module.incoming.len();
+        let fan_in = module
+            .incoming
+            .iter()
+            .filter_map(|dep| dep.source.as_deref())
+            .collect::<HashSet<_>>()
+            .len();
         metrics.push(QualityMetric {
             name: "fan_in".to_string(),
             value: fan_in as f64,
@@ -1,5 +1,5 @@
 use crate::rules::QualityRule;
-use crate::types::{ModuleIR, QualityMetric, Severity, Symbol, SymbolKind};
+use crate::types::{ModuleIR, QualityMetric, Severity, Symbol};
 
 pub struct FunctionSizeRule {
     pub max_loc: u32,
@@ -27,7 +27,7 @@ impl QualityRule for FunctionSizeRule {
         let large_functions: Vec<_> = module
             .symbols
             .iter()
-            .filter(|s| s.kind == SymbolKind::Function && s.loc > self.max_loc)
+            .filter(|s| s.kind.is_callable() && s.loc > self.max_loc)
             .collect();
 
         let mut metrics = vec![];
@@ -56,7 +56,7 @@ impl QualityRule for FunctionSizeRule {
     }
 
     fn check_symbol(&self, symbol: &Symbol) -> Vec<QualityMetric> {
-        if symbol.kind == SymbolKind::Function && symbol.loc > self.max_loc {
+        if symbol.kind.is_callable() && symbol.loc > self.max_loc {
             vec![QualityMetric {
                 name: "function_size".to_string(),
                 value: symbol.loc as f64,
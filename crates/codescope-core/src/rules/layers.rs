@@ -0,0 +1,140 @@
+use crate::graph::DependencyGraph;
+use crate::resolve::normalize;
+use crate::rules::{GraphMetric, QualityRule};
+use crate::types::{ModuleIR, QualityMetric, Severity, Symbol};
+use anyhow::{Context, Result};
+use globset::{GlobBuilder, GlobSet, GlobSetBuilder};
+use std::path::{Path, PathBuf};
+
+/// A named group of files, matched by glob
+pub struct Layer {
+    pub name: String,
+    globs: GlobSet,
+    /// Project root the globs are relative to
+    root: PathBuf,
+}
+
+impl Layer {
+    pub fn new(name: &str, patterns: &[String]) -> Result<Self> {
+        let mut builder = GlobSetBuilder::new();
+        for pattern in patterns {
+            let glob = GlobBuilder::new(pattern)
+                .literal_separator(true)
+                .build()
+                .with_context(|| format!("Invalid glob '{}' in layer '{}'", pattern, name))?;
+            builder.add(glob);
+        }
+        Ok(Self {
+            name: name.to_string(),
+            globs: builder.build()?,
+            root: PathBuf::new(),
+        })
+    }
+
+    /// Matches the globs against paths relative to `root` rather than as given
+    pub fn with_root(mut self, root: &Path) -> Self {
+        self.root = normalize(root);
+        self
+    }
+
+    /// Whether `path`, relative to the root when under it, matches one of the layer's globs
+    pub fn contains(&self, path: &str) -> bool {
+        let path = normalize(Path::new(path));
+        self.globs
+            .is_match(path.strip_prefix(&self.root).unwrap_or(&path))
+    }
+}
+
+/// Flags imports that go up the layer order or cross a forbidden boundary.
+/// Layers are ordered top to bottom: a layer may import itself and the layers below it.
+pub struct LayerRule {
+    layers: Vec<Layer>,
+    /// `(from, to)` layer names that must not depend on each other, whatever the order
+    forbidden: Vec<(String, String)>,
+    pub severity: Severity,
+}
+
+impl LayerRule {
+    pub fn new(layers: Vec<Layer>, forbidden: Vec<(String, String)>, severity: Severity) -> Self {
+        Self {
+            layers,
+            forbidden,
+            severity,
+        }
+    }
+
+    /// Index of the first layer matching `path`
+    fn layer_of(&self, path: &str) -> Option<usize> {
+        self.layers.iter().position(|layer| layer.contains(path))
+    }
+
+    fn violation(&self, from: usize, to: usize) -> Option<&'static str> {
+        let (from_name, to_name) = (&self.layers[from].name, &self.layers[to].name);
+        if self
+            .forbidden
+            .iter()
+            .any(|(f, t)| f == from_name && t == to_name)
+        {
+            Some("Forbidden dependency")
+        } else if from > to {
+            Some("Layer violation")
+        } else {
+            None
+        }
+    }
+}
+
+impl QualityRule for LayerRule {
+    fn name(&self) -> &str {
+        "layers"
+    }
+
+    fn check_module(&self, _module: &ModuleIR) -> Vec<QualityMetric> {
+        vec![]
+    }
+
+    fn check_symbol(&self, _symbol: &Symbol) -> Vec<QualityMetric> {
+        vec![]
+    }
+
+    fn check_graph(&self, graph: &DependencyGraph) -> Vec<GraphMetric> {
+        if self.layers.is_empty() {
+            return vec![];
+        }
+
+        let mut metrics = vec![];
+        for (source, target, edge) in graph.edges() {
+            let (Some(from), Some(to)) = (self.layer_of(source), self.layer_of(target)) else {
+                continue;
+            };
+            let Some(kind) = self.violation(from, to) else {
+                continue;
+            };
+
+            let line = edge
+                .span
+                .map(|span| format!(":{}", span.start_line))
+                .unwrap_or_default();
+            metrics.push(GraphMetric {
+                path: source.to_string(),
+                symbol: None,
+                metric: QualityMetric {
+                    name: "layer_violation".to_string(),
+                    value: 1.0,
+                    threshold: Some(0.0),
+                    severity: self.severity.clone(),
+                    message: Some(format!(
+                        "{}: {} must not import {} ({}{} → {})",
+                        kind, self.layers[from].name, self.layers[to].name, source, line, target
+                    )),
+                },
+            });
+        }
+
+        metrics
+    }
+}
+
+#[cfg(test)]
+#[path = "layers_test.rs"]
+mod layers_test;
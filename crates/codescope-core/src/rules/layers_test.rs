@@ -0,0 +1,98 @@
+#[cfg(test)]
+mod tests {
+    use crate::graph::DependencyGraph;
+    use crate::rules::layers::{Layer, LayerRule};
+    use crate::rules::QualityRule;
+    use crate::test_fixtures::module_importing;
+    use crate::types::Severity;
+    use std::path::Path;
+
+    fn create_test_rule(forbidden: &[(&str, &str)]) -> LayerRule {
+        let layer = |name: &str, pattern: &str| Layer::new(name, &[pattern.to_string()]).unwrap();
+        LayerRule::new(
+            vec![
+                layer("ui", "src/ui/**"),
+                layer("domain", "src/domain/**"),
+                layer("infra", "src/infra/**"),
+            ],
+            forbidden
+                .iter()
+                .map(|(from, to)| (from.to_string(), to.to_string()))
+                .collect(),
+            Severity::Error,
+        )
+    }
+
+    fn create_test_graph() -> DependencyGraph {
+        let mut modules = vec![
+            module_importing("src/ui/page.ts", &["src/domain/user.ts", "src/infra/db.ts"]),
+            module_importing("src/domain/user.ts", &["src/ui/format.ts"]),
+            module_importing("src/infra/db.ts", &["src/domain/user.ts"]),
+            module_importing("src/ui/format.ts", &["src/main.ts"]),
+            module_importing("src/main.ts", &[]),
+        ];
+        DependencyGraph::from_modules(&mut modules)
+    }
+
+    #[test]
+    fn test_upward_imports() {
+        let metrics = create_test_rule(&[]).check_graph(&create_test_graph());
+
+        let paths: Vec<&str> = metrics.iter().map(|m| m.path.as_str()).collect();
+        assert_eq!(paths, vec!["src/domain/user.ts", "src/infra/db.ts"]);
+        assert_eq!(
+            metrics[0].metric.message.as_deref(),
+            Some("Layer violation: domain must not import ui (src/domain/user.ts:1 → src/ui/format.ts)")
+        );
+        assert_eq!(metrics[0].metric.severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_forbidden_dependency() {
+        let metrics = create_test_rule(&[("ui", "infra")]).check_graph(&create_test_graph());
+
+        assert_eq!(metrics.len(), 3);
+        let forbidden = metrics.iter().find(|m| m.path == "src/ui/page.ts").unwrap();
+        assert!(forbidden
+            .metric
+            .message
+            .as_deref()
+            .unwrap()
+            .starts_with("Forbidden dependency: ui must not import infra"));
+    }
+
+    #[test]
+    fn test_layers_relative_to_root() {
+        let root = Path::new("/home/dev/project");
+        let layer = |name: &str, pattern: &str| {
+            Layer::new(name, &[pattern.to_string()])
+                .unwrap()
+                .with_root(root)
+        };
+        let rule = LayerRule::new(
+            vec![layer("ui", "src/ui/**"), layer("infra", "src/infra/**")],
+            vec![("ui".to_string(), "infra".to_string())],
+            Severity::Error,
+        );
+        let mut modules = vec![
+            module_importing(
+                "/home/dev/project/src/ui/page.ts",
+                &["/home/dev/project/src/infra/db.ts"],
+            ),
+            module_importing("/home/dev/project/src/infra/db.ts", &[]),
+        ];
+        let graph = DependencyGraph::from_modules(&mut modules);
+
+        // Absolute paths are matched relative to the root, so the run does not depend on
+        // how the analyzed directory was spelled
+        let metrics = rule.check_graph(&graph);
+        assert_eq!(metrics.len(), 1);
+        assert_eq!(metrics[0].path, "/home/dev/project/src/ui/page.ts");
+        assert!(layer("ui", "src/ui/**").contains("./src/ui/page.ts"));
+    }
+
+    #[test]
+    fn test_invalid_glob() {
+        assert!(Layer::new("ui", &["src/ui/[".to_string()]).is_err());
+    }
+}
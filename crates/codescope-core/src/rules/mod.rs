@@ -1,3 +1,4 @@
+use crate::graph::DependencyGraph;
 use crate::types::{ModuleIR, QualityMetric, Symbol};
 
 pub mod file_size;
@@ -5,11 +6,38 @@ pub mod function_size;
 pub mod coupling;
 pub mod structure_stats;
 pub mod complexity;
+pub mod api_surface;
+pub mod barrels;
+pub mod no_cycles;
+pub mod layers;
+pub mod stable_dependencies;
+pub mod centrality;
+pub mod unused_exports;
+pub mod broken_imports;
 
 pub trait QualityRule: Send + Sync {
     fn name(&self) -> &str;
     fn check_module(&self, module: &ModuleIR) -> Vec<QualityMetric>;
     fn check_symbol(&self, symbol: &Symbol) -> Vec<QualityMetric>;
+
+    /// Repository-wide checks, run once the dependency graph has been built
+    fn check_graph(&self, _graph: &DependencyGraph) -> Vec<GraphMetric> {
+        vec![]
+    }
+
+    /// Repository-wide checks that also need the parsed modules, e.g. their exports
+    fn check_modules(&self, _graph: &DependencyGraph, _modules: &[ModuleIR]) -> Vec<GraphMetric> {
+        vec![]
+    }
+}
+
+/// A metric from a graph check, attributed to one module or to a top-level symbol in it
+#[derive(Debug, Clone)]
+pub struct GraphMetric {
+    pub path: String,
+    /// Name of the symbol the metric belongs to; `None` for the module itself
+    pub symbol: Option<String>,
+    pub metric: QualityMetric,
 }
 
 pub struct RuleRegistry {
@@ -38,6 +66,20 @@ impl RuleRegistry {
             .flat_map(|rule| rule.check_symbol(symbol))
             .collect()
     }
+
+    pub fn check_graph(&self, graph: &DependencyGraph) -> Vec<GraphMetric> {
+        self.rules
+            .iter()
+            .flat_map(|rule| rule.check_graph(graph))
+            .collect()
+    }
+
+    pub fn check_modules(&self, graph: &DependencyGraph, modules: &[ModuleIR]) -> Vec<GraphMetric> {
+        self.rules
+            .iter()
+            .flat_map(|rule| rule.check_modules(graph, modules))
+            .collect()
+    }
 }
 
 impl Default for RuleRegistry {
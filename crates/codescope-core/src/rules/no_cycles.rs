@@ -0,0 +1,60 @@
+use crate::graph::DependencyGraph;
+use crate::rules::{GraphMetric, QualityRule};
+use crate::types::{ModuleIR, QualityMetric, Severity, Symbol};
+
+/// Flags every module that is part of a runtime import cycle
+pub struct NoCyclesRule {
+    pub severity: Severity,
+}
+
+impl NoCyclesRule {
+    pub fn new(severity: Severity) -> Self {
+        Self { severity }
+    }
+}
+
+impl Default for NoCyclesRule {
+    fn default() -> Self {
+        Self::new(Severity::Warning)
+    }
+}
+
+impl QualityRule for NoCyclesRule {
+    fn name(&self) -> &str {
+        "no_cycles"
+    }
+
+    fn check_module(&self, _module: &ModuleIR) -> Vec<QualityMetric> {
+        vec![]
+    }
+
+    fn check_symbol(&self, _symbol: &Symbol) -> Vec<QualityMetric> {
+        vec![]
+    }
+
+    fn check_graph(&self, graph: &DependencyGraph) -> Vec<GraphMetric> {
+        let mut metrics = vec![];
+
+        for cycle in graph.cycles() {
+            let mut loop_path: Vec<&str> = cycle.path.iter().map(|s| s.source.as_str()).collect();
+            loop_path.extend(cycle.path.first().map(|s| s.source.as_str()));
+            let message = format!("Part of an import cycle: {}", loop_path.join(" → "));
+
+            for path in &cycle.modules {
+                metrics.push(GraphMetric {
+                    path: path.clone(),
+                    symbol: None,
+                    metric: QualityMetric {
+                        name: "import_cycle".to_string(),
+                        value: cycle.modules.len() as f64,
+                        threshold: None,
+                        severity: self.severity.clone(),
+                        message: Some(message.clone()),
+                    },
+                });
+            }
+        }
+
+        metrics
+    }
+}
@@ -0,0 +1,81 @@
+use crate::components::{coupling, instability, Components};
+use crate::graph::DependencyGraph;
+use crate::rules::{GraphMetric, QualityRule};
+use crate::types::{ModuleIR, QualityMetric, Severity, Symbol};
+
+/// Stable Dependencies Principle: flags imports from a component into a less stable one
+pub struct StableDependenciesRule {
+    components: Components,
+    pub severity: Severity,
+}
+
+impl StableDependenciesRule {
+    pub fn new(components: Components, severity: Severity) -> Self {
+        Self {
+            components,
+            severity,
+        }
+    }
+}
+
+impl QualityRule for StableDependenciesRule {
+    fn name(&self) -> &str {
+        "stable_dependencies"
+    }
+
+    fn check_module(&self, _module: &ModuleIR) -> Vec<QualityMetric> {
+        vec![]
+    }
+
+    fn check_symbol(&self, _symbol: &Symbol) -> Vec<QualityMetric> {
+        vec![]
+    }
+
+    fn check_graph(&self, graph: &DependencyGraph) -> Vec<GraphMetric> {
+        let stability: Vec<_> = coupling(graph, &self.components)
+            .into_iter()
+            .map(|(name, (ca, ce))| (name, instability(ca, ce)))
+            .collect();
+        let instability_of = |name: &str| {
+            stability
+                .iter()
+                .find(|(n, _)| n == name)
+                .map_or(0.0, |(_, i)| *i)
+        };
+
+        let mut metrics = vec![];
+        for (source, target, edge) in graph.edges() {
+            let (Some(from), Some(to)) = (
+                self.components.component_of(source),
+                self.components.component_of(target),
+            ) else {
+                continue;
+            };
+            let (from_i, to_i) = (instability_of(&from), instability_of(&to));
+            if from == to || to_i <= from_i {
+                continue;
+            }
+
+            let line = edge
+                .span
+                .map(|span| format!(":{}", span.start_line))
+                .unwrap_or_default();
+            metrics.push(GraphMetric {
+                path: source.to_string(),
+                symbol: None,
+                metric: QualityMetric {
+                    name: "unstable_dependency".to_string(),
+                    value: to_i,
+                    threshold: Some(from_i),
+                    severity: self.severity.clone(),
+                    message: Some(format!(
+                        "Depends on a less stable component: {} (I={:.2}) imports {} (I={:.2}) ({}{} → {})",
+                        from, from_i, to, to_i, source, line, target
+                    )),
+                },
+            });
+        }
+
+        metrics
+    }
+}
@@ -41,6 +41,11 @@ impl QualityRule for StructureStatsRule {
             .iter()
             .filter(|s| s.kind == SymbolKind::Function)
             .count();
+        let method_count = module
+            .symbols
+            .iter()
+            .filter(|s| s.kind == SymbolKind::Method)
+            .count();
         let class_count = module
             .symbols
             .iter()
@@ -91,6 +96,14 @@ impl QualityRule for StructureStatsRule {
             message: None,
         });
 
+        metrics.push(QualityMetric {
+            name: "method_count".to_string(),
+            value: method_count as f64,
+            threshold: None,
+            severity: Severity::Info,
+            message: None,
+        });
+
         metrics.push(QualityMetric {
             name: "interface_count".to_string(),
             value: interface_count as f64,
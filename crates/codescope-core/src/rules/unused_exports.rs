@@ -0,0 +1,81 @@
+use crate::exports::ExportIndex;
+use crate::graph::DependencyGraph;
+use crate::reachability::EntryPoints;
+use crate::rules::{GraphMetric, QualityRule};
+use crate::types::{ModuleIR, QualityMetric, Severity, Symbol};
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+/// Flags exports that no other module imports. Exports of the entry points are public API
+/// and never flagged.
+pub struct UnusedExportsRule {
+    entry_points: EntryPoints,
+    /// Project root the entry point globs and `package.json` are looked up in
+    root: PathBuf,
+    pub severity: Severity,
+}
+
+impl UnusedExportsRule {
+    pub fn new(entry_points: EntryPoints, root: PathBuf, severity: Severity) -> Self {
+        Self {
+            entry_points,
+            root,
+            severity,
+        }
+    }
+}
+
+impl QualityRule for UnusedExportsRule {
+    fn name(&self) -> &str {
+        "unused_exports"
+    }
+
+    fn check_module(&self, _module: &ModuleIR) -> Vec<QualityMetric> {
+        vec![]
+    }
+
+    fn check_symbol(&self, _symbol: &Symbol) -> Vec<QualityMetric> {
+        vec![]
+    }
+
+    fn check_modules(&self, graph: &DependencyGraph, modules: &[ModuleIR]) -> Vec<GraphMetric> {
+        let entry_points = self.entry_points.find(graph, &self.root);
+        let mut unused: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+        let mut metrics = vec![];
+
+        for (path, export) in ExportIndex::new(modules).unused_exports(&entry_points) {
+            unused.entry(path).or_default().push(&export.name);
+            metrics.push(GraphMetric {
+                path: path.to_string(),
+                symbol: Some(export.local.clone().unwrap_or_else(|| export.name.clone())),
+                metric: QualityMetric {
+                    name: "unused_export".to_string(),
+                    value: 1.0,
+                    threshold: None,
+                    severity: self.severity.clone(),
+                    message: Some(format!("Export '{}' is never imported", export.name)),
+                },
+            });
+        }
+
+        for (path, names) in unused {
+            metrics.push(GraphMetric {
+                path: path.to_string(),
+                symbol: None,
+                metric: QualityMetric {
+                    name: "unused_export_count".to_string(),
+                    value: names.len() as f64,
+                    threshold: Some(0.0),
+                    severity: self.severity.clone(),
+                    message: Some(format!(
+                        "{} exports are never imported: {}",
+                        names.len(),
+                        names.join(", ")
+                    )),
+                },
+            });
+        }
+
+        metrics
+    }
+}
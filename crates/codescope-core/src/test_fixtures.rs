@@ -0,0 +1,151 @@
+//! Modules, symbols, edges, metrics and files shared by the unit tests
+
+use crate::types::{
+    BindingKind, DepEdge, DepKind, Export, ImportBinding, ImportKind, ModuleIR, QualityMetric,
+    ResolutionKind, Severity, Span, Symbol, SymbolKind, Visibility,
+};
+use std::fs;
+use std::path::Path;
+
+/// Empty TypeScript module of 10 lines
+pub fn module(path: &str) -> ModuleIR {
+    ModuleIR {
+        path: path.to_string(),
+        language: Some("typescript".to_string()),
+        loc: 10,
+        comment_lines: 0,
+        blank_lines: 0,
+        symbols: vec![],
+        exports: vec![],
+        dynamic_exports: false,
+        metrics: vec![],
+        outgoing: vec![],
+        incoming: vec![],
+    }
+}
+
+/// Module with one import of each of `imports`, the n-th on line n
+pub fn module_importing(path: &str, imports: &[&str]) -> ModuleIR {
+    ModuleIR {
+        outgoing: imports
+            .iter()
+            .zip(1..)
+            .map(|(target, line)| DepEdge {
+                span: Some(line_span(line)),
+                ..edge(target)
+            })
+            .collect(),
+        ..module(path)
+    }
+}
+
+/// Module exporting `exports` under their own names
+pub fn module_exporting(path: &str, exports: &[&str], outgoing: Vec<DepEdge>) -> ModuleIR {
+    ModuleIR {
+        exports: exports
+            .iter()
+            .map(|name| Export {
+                name: name.to_string(),
+                local: Some(name.to_string()),
+                type_only: false,
+                aggregate: false,
+                span: None,
+            })
+            .collect(),
+        outgoing,
+        ..module(path)
+    }
+}
+
+/// Exported, non-abstract symbol of 5 lines
+pub fn symbol(name: &str, kind: SymbolKind) -> Symbol {
+    Symbol {
+        kind,
+        name: name.to_string(),
+        loc: 5,
+        cyclomatic_complexity: None,
+        parent: None,
+        visibility: Visibility::Exported,
+        is_abstract: false,
+        span: None,
+        metrics: vec![],
+    }
+}
+
+/// Import of `target` without bindings, of unknown kind and not yet resolved
+pub fn edge(target: &str) -> DepEdge {
+    DepEdge {
+        source: None,
+        target: Some(target.to_string()),
+        relation: DepKind::Import,
+        strength: 0.6,
+        files: None,
+        span: None,
+        import_kind: None,
+        type_only: false,
+        bindings: vec![],
+        specifier: None,
+        resolution: None,
+    }
+}
+
+/// Import of `target` resolved as `resolution`, weighted `strength`
+pub fn resolved_edge(target: &str, resolution: ResolutionKind, strength: f32) -> DepEdge {
+    DepEdge {
+        strength,
+        resolution: Some(resolution),
+        ..edge(target)
+    }
+}
+
+/// Import of `target` with `(imported, local)` bindings; `*` as imported makes a
+/// namespace binding
+pub fn import_edge(target: &str, kind: ImportKind, bindings: &[(&str, &str)]) -> DepEdge {
+    DepEdge {
+        import_kind: Some(kind),
+        bindings: bindings
+            .iter()
+            .map(|(imported, local)| ImportBinding {
+                kind: match *imported {
+                    "*" => BindingKind::Namespace,
+                    "default" => BindingKind::Default,
+                    _ => BindingKind::Named,
+                },
+                imported: imported.to_string(),
+                local: local.to_string(),
+                type_only: false,
+            })
+            .collect(),
+        ..edge(target)
+    }
+}
+
+/// Import statement on `line`
+pub fn line_span(line: u32) -> Span {
+    Span {
+        start_line: line,
+        start_column: 1,
+        end_line: line,
+        end_column: 30,
+        start_byte: 0,
+        end_byte: 0,
+    }
+}
+
+/// Metric without a threshold or message
+pub fn metric(name: &str, value: f64, severity: Severity) -> QualityMetric {
+    QualityMetric {
+        name: name.to_string(),
+        value,
+        threshold: None,
+        severity,
+        message: None,
+    }
+}
+
+/// Writes `content` to `path` under `root`, creating its directories
+pub fn write(root: &Path, path: &str, content: &str) {
+    let path = root.join(path);
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, content).unwrap();
+}
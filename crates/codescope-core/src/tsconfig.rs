@@ -0,0 +1,252 @@
+use crate::resolve::normalize;
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// Config file names looked up next to a source file, nearest first
+const CONFIG_NAMES: &[&str] = &["tsconfig.json", "jsconfig.json"];
+
+/// Guards against `extends` cycles
+const MAX_EXTENDS_DEPTH: usize = 16;
+
+/// Module resolution settings from a `tsconfig.json` / `jsconfig.json`, with `extends` applied
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TsConfig {
+    /// `compilerOptions.baseUrl`, relative to the config that declared it
+    pub base_url: Option<PathBuf>,
+    /// `compilerOptions.paths`, e.g. `@app/core/*` -> `["packages/core/src/*"]`
+    pub paths: Vec<PathAlias>,
+    /// Directory of the config declaring `paths`; targets resolve here when there is no `baseUrl`
+    pub paths_dir: PathBuf,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PathAlias {
+    pub pattern: String,
+    pub targets: Vec<String>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct RawConfig {
+    #[serde(default)]
+    extends: Option<Extends>,
+    #[serde(default)]
+    compiler_options: RawCompilerOptions,
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Extends {
+    One(String),
+    Many(Vec<String>),
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct RawCompilerOptions {
+    #[serde(default)]
+    base_url: Option<String>,
+    #[serde(default)]
+    paths: Option<serde_json::Map<String, serde_json::Value>>,
+}
+
+impl TsConfig {
+    /// Nearest `tsconfig.json` or `jsconfig.json` in `dir` or its ancestors
+    pub fn find(dir: &Path) -> Option<PathBuf> {
+        dir.ancestors().find_map(|ancestor| {
+            CONFIG_NAMES
+                .iter()
+                .map(|name| ancestor.join(name))
+                .find(|path| path.is_file())
+        })
+    }
+
+    /// Reads `path` and the configs it extends
+    pub fn load(path: &Path) -> Result<Self> {
+        Self::load_at_depth(path, 0)
+    }
+
+    fn load_at_depth(path: &Path, depth: usize) -> Result<Self> {
+        if depth > MAX_EXTENDS_DEPTH {
+            anyhow::bail!("tsconfig extends chain too deep at {}", path.display());
+        }
+
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let raw: RawConfig = serde_json::from_str(&strip_jsonc(&content))
+            .with_context(|| format!("Failed to parse {}", path.display()))?;
+        let dir = path.parent().unwrap_or_else(|| Path::new(""));
+
+        let parents = match raw.extends {
+            Some(Extends::One(parent)) => vec![parent],
+            Some(Extends::Many(parents)) => parents,
+            None => vec![],
+        };
+        // Later entries in an `extends` array override earlier ones
+        let mut config = TsConfig {
+            paths_dir: dir.to_path_buf(),
+            ..Default::default()
+        };
+        for parent in parents {
+            if let Some(parent_path) = find_extended(dir, &parent) {
+                let parent = Self::load_at_depth(&parent_path, depth + 1)?;
+                config.base_url = parent.base_url.or(config.base_url);
+                if !parent.paths.is_empty() {
+                    config.paths = parent.paths;
+                    config.paths_dir = parent.paths_dir;
+                }
+            }
+        }
+
+        let options = raw.compiler_options;
+        if let Some(base_url) = options.base_url {
+            config.base_url = Some(normalize(&dir.join(base_url)));
+        }
+        if let Some(paths) = options.paths {
+            config.paths = paths
+                .into_iter()
+                .map(|(pattern, targets)| PathAlias {
+                    pattern,
+                    targets: targets
+                        .as_array()
+                        .map(|targets| {
+                            targets
+                                .iter()
+                                .filter_map(|t| t.as_str().map(String::from))
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                })
+                .collect();
+            config.paths_dir = dir.to_path_buf();
+        }
+
+        Ok(config)
+    }
+
+    /// Paths a bare specifier may refer to, in the order TypeScript tries them:
+    /// the best-matching `paths` alias, then `baseUrl`
+    pub fn candidates(&self, specifier: &str) -> Vec<PathBuf> {
+        let paths_base = self.base_url.as_deref().unwrap_or(&self.paths_dir);
+        let mut candidates: Vec<PathBuf> = self
+            .best_alias(specifier)
+            .map(|(alias, matched)| {
+                alias
+                    .targets
+                    .iter()
+                    .map(|target| normalize(&paths_base.join(target.replacen('*', matched, 1))))
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        if let Some(base_url) = &self.base_url {
+            candidates.push(normalize(&base_url.join(specifier)));
+        }
+        candidates
+    }
+
+    /// Exact patterns win; among wildcards the longest prefix does
+    fn best_alias<'a>(&self, specifier: &'a str) -> Option<(&PathAlias, &'a str)> {
+        if let Some(alias) = self.paths.iter().find(|a| a.pattern == specifier) {
+            return Some((alias, ""));
+        }
+        self.paths
+            .iter()
+            .filter_map(|alias| {
+                let (prefix, suffix) = alias.pattern.split_once('*')?;
+                let matched = specifier
+                    .strip_prefix(prefix)?
+                    .strip_suffix(suffix)
+                    .filter(|_| specifier.len() >= prefix.len() + suffix.len())?;
+                Some((alias, prefix.len(), matched))
+            })
+            .max_by_key(|(_, prefix_len, _)| *prefix_len)
+            .map(|(alias, _, matched)| (alias, matched))
+    }
+}
+
+/// Locates an `extends` entry: a relative path (`.json` optional) or a package in `node_modules`
+fn find_extended(dir: &Path, extends: &str) -> Option<PathBuf> {
+    let with_json = |path: PathBuf| {
+        if path.is_file() {
+            Some(path)
+        } else if path.is_dir() {
+            Some(path.join("tsconfig.json")).filter(|p| p.is_file())
+        } else {
+            let mut json = path.into_os_string();
+            json.push(".json");
+            Some(PathBuf::from(json)).filter(|p| p.is_file())
+        }
+    };
+
+    if extends.starts_with('.') || Path::new(extends).is_absolute() {
+        return with_json(normalize(&dir.join(extends)));
+    }
+    dir.ancestors()
+        .find_map(|ancestor| with_json(ancestor.join("node_modules").join(extends)))
+}
+
+/// tsconfig files are JSONC: drop comments and trailing commas so serde_json accepts them
+pub(crate) fn strip_jsonc(content: &str) -> String {
+    let mut output = String::with_capacity(content.len());
+    let mut chars = content.char_indices().peekable();
+    let mut in_string = false;
+
+    while let Some((i, c)) = chars.next() {
+        if in_string {
+            output.push(c);
+            match c {
+                '\\' => output.extend(chars.next().map(|(_, escaped)| escaped)),
+                '"' => in_string = false,
+                _ => {}
+            }
+            continue;
+        }
+        let next = chars.peek().map(|&(_, next)| next);
+        match c {
+            '"' => {
+                in_string = true;
+                output.push(c);
+            }
+            '/' if next == Some('/') => while chars.next_if(|&(_, next)| next != '\n').is_some() {},
+            '/' if next == Some('*') => {
+                chars.next();
+                let mut previous = '\0';
+                for (_, next) in chars.by_ref() {
+                    if previous == '*' && next == '/' {
+                        break;
+                    }
+                    previous = next;
+                }
+            }
+            ',' => {
+                // Trailing comma: the next significant character closes the object or array
+                let rest = strip_leading_comments(&content[i + 1..]);
+                if !rest.starts_with('}') && !rest.starts_with(']') {
+                    output.push(c);
+                }
+            }
+            _ => output.push(c),
+        }
+    }
+    output
+}
+
+fn strip_leading_comments(mut rest: &str) -> &str {
+    loop {
+        rest = rest.trim_start();
+        if let Some(after) = rest.strip_prefix("//") {
+            rest = after.split_once('\n').map_or("", |(_, tail)| tail);
+        } else if let Some(after) = rest.strip_prefix("/*") {
+            rest = after.split_once("*/").map_or("", |(_, tail)| tail);
+        } else {
+            return rest;
+        }
+    }
+}
+
+#[cfg(test)]
+#[path = "tsconfig_test.rs"]
+mod tsconfig_test;
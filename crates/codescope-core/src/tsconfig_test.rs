@@ -0,0 +1,95 @@
+#[cfg(test)]
+mod tests {
+    use crate::test_fixtures::write;
+    use crate::tsconfig::{strip_jsonc, TsConfig};
+    use std::fs;
+
+    #[test]
+    fn test_strip_jsonc() {
+        let content = r#"{
+  // line comment
+  "a": "http://example.com", /* block */
+  "b": [1, 2,],
+  "c": { "d": "/* not a comment */", },
+}"#;
+        let value: serde_json::Value = serde_json::from_str(&strip_jsonc(content)).unwrap();
+        assert_eq!(value["a"], "http://example.com");
+        assert_eq!(value["b"], serde_json::json!([1, 2]));
+        assert_eq!(value["c"]["d"], "/* not a comment */");
+    }
+
+    #[test]
+    fn test_load_follows_extends() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "tsconfig.base.json",
+            r#"{
+  "compilerOptions": {
+    "baseUrl": ".",
+    "paths": { "@app/core/*": ["packages/core/src/*"] },
+  },
+}"#,
+        );
+        write(
+            root,
+            "apps/web/tsconfig.json",
+            r#"{ "extends": "../../tsconfig.base", "compilerOptions": { "strict": true } }"#,
+        );
+
+        let config = TsConfig::load(&root.join("apps/web/tsconfig.json")).unwrap();
+        assert_eq!(config.base_url, Some(root.to_path_buf()));
+        assert_eq!(config.paths.len(), 1);
+        assert_eq!(
+            config.candidates("@app/core/math"),
+            vec![
+                root.join("packages/core/src/math"),
+                root.join("@app/core/math")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_paths_without_base_url() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(
+            root,
+            "web/jsconfig.json",
+            r#"{ "compilerOptions": { "paths": { "~/*": ["./src/*"], "~/lib/*": ["./vendor/*"], "config": ["./config/index.js"] } } }"#,
+        );
+
+        let config = TsConfig::load(&root.join("web/jsconfig.json")).unwrap();
+        assert_eq!(config.candidates("~/app"), vec![root.join("web/src/app")]);
+        // Longest matching prefix wins
+        assert_eq!(
+            config.candidates("~/lib/x"),
+            vec![root.join("web/vendor/x")]
+        );
+        assert_eq!(
+            config.candidates("config"),
+            vec![root.join("web/config/index.js")]
+        );
+        assert!(config.candidates("react").is_empty());
+    }
+
+    #[test]
+    fn test_find_nearest() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        write(root, "tsconfig.json", "{}");
+        write(root, "packages/ui/jsconfig.json", "{}");
+        fs::create_dir_all(root.join("packages/ui/src")).unwrap();
+        fs::create_dir_all(root.join("packages/core/src")).unwrap();
+
+        assert_eq!(
+            TsConfig::find(&root.join("packages/ui/src")),
+            Some(root.join("packages/ui/jsconfig.json"))
+        );
+        assert_eq!(
+            TsConfig::find(&root.join("packages/core/src")),
+            Some(root.join("tsconfig.json"))
+        );
+    }
+}
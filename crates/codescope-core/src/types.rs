@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
 #[serde(rename_all = "lowercase")]
 pub enum Severity {
     Info,
@@ -19,11 +19,24 @@ pub struct QualityMetric {
     pub message: Option<String>,
 }
 
+/// Location of a syntax node in its source file.
+/// Lines and columns are 1-based; `start_byte..end_byte` is the byte range.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Span {
+    pub start_line: u32,
+    pub start_column: u32,
+    pub end_line: u32,
+    pub end_column: u32,
+    pub start_byte: usize,
+    pub end_byte: usize,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 #[serde(rename_all = "lowercase")]
 pub enum SymbolKind {
     Class,
     Function,
+    Method,
     Const,
     Variable,
     Interface,
@@ -31,6 +44,28 @@ pub enum SymbolKind {
     Enum,
 }
 
+impl SymbolKind {
+    /// Functions and methods: the symbols that carry a cyclomatic complexity.
+    pub fn is_callable(&self) -> bool {
+        matches!(self, SymbolKind::Function | SymbolKind::Method)
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Visibility {
+    #[default]
+    Internal,
+    Exported,
+    DefaultExport,
+}
+
+impl Visibility {
+    pub fn is_exported(&self) -> bool {
+        *self != Visibility::Internal
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Symbol {
     pub kind: SymbolKind,
@@ -38,6 +73,16 @@ pub struct Symbol {
     pub loc: u32,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub cyclomatic_complexity: Option<u32>,
+    /// Enclosing class for methods (the `name` is already qualified, e.g. `DataStore.process`)
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub parent: Option<String>,
+    #[serde(default)]
+    pub visibility: Visibility,
+    /// `abstract class`: counts towards a component's abstractness
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub is_abstract: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub span: Option<Span>,
     #[serde(default)]
     pub metrics: Vec<QualityMetric>,
 }
@@ -52,6 +97,65 @@ pub enum DepKind {
     Compose,
 }
 
+/// Syntax used to declare an import dependency
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportKind {
+    /// `import x from "y"`
+    Static,
+    /// `import("y")`, loaded lazily at runtime
+    Dynamic,
+    /// CommonJS `require("y")` or TypeScript `import x = require("y")`
+    Require,
+    /// `export * from "y"`, `export { x } from "y"`
+    ReExport,
+    /// `import "y"`: evaluated for its side effects, binds nothing
+    SideEffect,
+}
+
+impl ImportKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImportKind::Static => "static",
+            ImportKind::Dynamic => "dynamic",
+            ImportKind::Require => "require",
+            ImportKind::ReExport => "re-export",
+            ImportKind::SideEffect => "side-effect",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum ResolutionKind {
+    /// `target` is a file in the repository
+    Internal,
+    /// `target` is the package name, e.g. `react` or `@scope/pkg`
+    External,
+    /// Relative specifier with no matching file; `target` keeps the specifier
+    Unresolved,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BindingKind {
+    Default,
+    Named,
+    Namespace,
+}
+
+/// A name brought in by an import or re-export, e.g. `{ join as joinPath }`
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ImportBinding {
+    pub kind: BindingKind,
+    /// Name exported by the target module (`default` for default, `*` for namespace bindings)
+    pub imported: String,
+    /// Name bound in the importing module; for re-exports, the name it is exported under
+    pub local: String,
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub type_only: bool,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DepEdge {
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -62,6 +166,79 @@ pub struct DepEdge {
     pub strength: f32,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub files: Option<u32>,
+    /// Import site in the source module
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub span: Option<Span>,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub import_kind: Option<ImportKind>,
+    /// `import type` / `export type`: erased at compile time, no runtime dependency
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub type_only: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub bindings: Vec<ImportBinding>,
+    /// Module specifier as written in the source, e.g. `../utils`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub specifier: Option<String>,
+    /// How `target` was resolved; `None` until the import resolver has run
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub resolution: Option<ResolutionKind>,
+}
+
+impl DepEdge {
+    /// Relation plus import style, e.g. `import`, `import:type`, `import:dynamic`
+    pub fn relation_label(&self) -> String {
+        let mut relation = format!("{:?}", self.relation).to_lowercase();
+        if let Some(kind) = self.import_kind.filter(|k| *k != ImportKind::Static) {
+            relation.push(':');
+            relation.push_str(kind.as_str());
+        }
+        if self.type_only {
+            relation.push_str(":type");
+        }
+        relation
+    }
+}
+
+/// The real edge behind an import of a barrel: the module declaring the imported names,
+/// reached by following the barrel's re-exports
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RealEdge {
+    pub source: String,
+    /// Module declaring the names
+    pub target: String,
+    /// Modules forwarding the names, starting with the one `source` imports
+    pub via: Vec<String>,
+    /// Names as imported by `source`
+    pub names: Vec<String>,
+    /// Import site in `source`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub span: Option<Span>,
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub type_only: bool,
+}
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
+
+/// A name in the module's own public surface. Re-exports (`export ... from`)
+/// are recorded as `ImportKind::ReExport` edges in `outgoing` instead.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Export {
+    /// Exported name, `default` for the default export
+    pub name: String,
+    /// Local binding behind the export, when it has one
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub local: Option<String>,
+    /// Interfaces, type aliases and `export type { ... }`
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub type_only: bool,
+    /// Default export that only bundles the exports listed next to it, as in CommonJS
+    /// `module.exports = { a, b }`
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub aggregate: bool,
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub span: Option<Span>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -77,6 +254,12 @@ pub struct ModuleIR {
     #[serde(default)]
     pub symbols: Vec<Symbol>,
     #[serde(default)]
+    pub exports: Vec<Export>,
+    /// `module.exports` or `export =` is assigned a value other than an object literal,
+    /// so the exported names are not known
+    #[serde(skip_serializing_if = "is_false", default)]
+    pub dynamic_exports: bool,
+    #[serde(default)]
     pub metrics: Vec<QualityMetric>,
     #[serde(default)]
     pub outgoing: Vec<DepEdge>,
@@ -92,10 +275,161 @@ pub struct FileMetrics {
     pub risk_hotspots: Vec<String>,
 }
 
+/// Modules that import each other, directly or transitively
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct Cycle {
+    /// Every module in the strongly connected component, sorted
+    pub modules: Vec<String>,
+    /// One loop through the component, starting and ending at `modules[0]`
+    pub path: Vec<CycleStep>,
+}
+
+/// One import along a cycle or an import path
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CycleStep {
+    pub source: String,
+    pub target: String,
+    /// Import site in `source`
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub span: Option<Span>,
+}
+
+/// JSON output of a directory run
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectoryReport {
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub directory: Option<DirectoryIR>,
+    pub modules: Vec<ModuleIR>,
+    #[serde(default)]
+    pub cycles: Vec<Cycle>,
+    /// Directory-wide fan-in/fan-out and the riskiest modules, riskiest first
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub summary: Option<FileMetrics>,
+    /// Imports through barrels, with the modules they really depend on
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub barrel_imports: Vec<RealEdge>,
+}
+
+/// JSON output of `codescope path`: import chains from `from` to `to`, shortest first
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PathReport {
+    pub from: String,
+    pub to: String,
+    pub paths: Vec<Vec<CycleStep>>,
+}
+
+/// JSON output of `codescope clusters`: import communities compared to the directory layout
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClusterReport {
+    /// Modularity of the detected clusters
+    pub modularity: f64,
+    /// Modularity of the directory layout, for comparison
+    pub directory_modularity: f64,
+    pub clusters: Vec<Cluster>,
+    /// Modules more tied to their cluster's directory than to their own
+    pub misplaced: Vec<MisplacedModule>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Cluster {
+    /// Directory holding most of the cluster's modules
+    pub directory: String,
+    /// Every directory the cluster spans, most modules first
+    pub directories: Vec<String>,
+    pub modules: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MisplacedModule {
+    pub path: String,
+    pub directory: String,
+    pub suggested: String,
+    /// Imports to or from modules in its own directory
+    pub own_links: u32,
+    /// Imports to or from modules in the suggested directory
+    pub suggested_links: u32,
+}
+
+/// JSON output of `codescope unreachable`: modules no entry point imports, even indirectly
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnreachableReport {
+    pub entry_points: Vec<String>,
+    pub unreachable: Vec<UnreachableModule>,
+    /// Number of unreachable modules
+    pub files: u32,
+    /// Total LOC of the unreachable modules
+    pub loc: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UnreachableModule {
+    pub path: String,
+    pub loc: u32,
+}
+
+/// JSON output of `codescope impact`: modules affected by a change to `targets`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImpactReport {
+    pub targets: Vec<String>,
+    pub affected: Vec<AffectedModule>,
+    /// Number of affected modules
+    pub files: u32,
+    /// Total LOC of the affected modules
+    pub loc: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AffectedModule {
+    pub path: String,
+    /// Import hops to the nearest target: 1 for direct importers
+    pub distance: u32,
+    pub loc: u32,
+}
+
+/// A directory with its module edges aggregated to directory level: edges point at other
+/// directories (or packages) and `DepEdge::files` counts the files behind each one
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectoryIR {
+    pub path: String,
+    #[serde(default)]
+    pub children: Vec<ChildEntry>,
+    #[serde(default)]
+    pub outgoing: Vec<DepEdge>,
+    #[serde(default)]
+    pub incoming: Vec<DepEdge>,
+    pub metrics: DirectoryMetrics,
+    /// Package metrics of the components under this directory
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub components: Vec<ComponentMetrics>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct DirectoryMetrics {
     pub files: u32,
     pub loc: u32,
+    /// Distinct directories and packages this directory imports
+    #[serde(default)]
+    pub fan_out: u32,
+    /// Distinct directories importing this one
+    #[serde(default)]
+    pub fan_in: u32,
+}
+
+/// Robert C. Martin's package metrics for a component (a directory or an architecture layer)
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq)]
+pub struct ComponentMetrics {
+    pub name: String,
+    pub files: u32,
+    /// Ca: modules outside the component that import it
+    pub afferent: u32,
+    /// Ce: modules outside the component that it imports
+    pub efferent: u32,
+    /// I = Ce / (Ca + Ce), from 0 (stable) to 1 (unstable)
+    pub instability: f64,
+    /// A: share of interfaces, type aliases and abstract classes among its types
+    pub abstractness: f64,
+    /// D = |A + I - 1|, distance from the main sequence
+    pub distance: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]